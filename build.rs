@@ -16,8 +16,13 @@
 // limitations under the License.
 // =================================================================================================
 
+#![allow(clippy::needless_return)]
+
 use std::{env, path};
 
+#[path = "builder/importer.rs"]
+mod importer;
+
 #[path = "builder/core_error.rs"]
 mod core_error;
 
@@ -34,5 +39,34 @@ fn main() {
 
     core_error::import(&core_path.join("src/error.rs"), &gen_path.join("src/error.rs"));
 
-    std_io::import(&std_path.join("src/io"), &gen_path.join("src/io"));
+    let removals = importer::RemovalLog::default();
+    std_io::import(&std_path.join("src/io"), &gen_path.join("src/io"), &removals);
+
+    // Shows the beginning of a generated file as build warnings if requested, e.g.
+    // `EROC_MICROSTD_PREVIEW=src/io/mod.rs EROC_MICROSTD_PREVIEW_LINES=100 cargo build`.
+    if let Ok(name) = env::var("EROC_MICROSTD_PREVIEW") {
+        let max_lines = env::var("EROC_MICROSTD_PREVIEW_LINES")
+            .ok()
+            .and_then(|value| value.parse::<usize>().ok())
+            .unwrap_or(40);
+
+        for line in importer::preview_file(&gen_path.join(name), max_lines) {
+            println!("cargo:warning={}", line);
+        }
+    }
+
+    // Shows which rule removed each block of code as build warnings if requested, e.g.
+    // `EROC_MICROSTD_REMOVALS=1 cargo build`.
+    if env::var_os("EROC_MICROSTD_REMOVALS").is_some() {
+        for line in importer::report_removals(&removals) {
+            println!("cargo:warning={}", line);
+        }
+    }
+
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=builder");
+    println!("cargo:rerun-if-env-changed=RUSTLIB_PATH");
+    println!("cargo:rerun-if-env-changed=EROC_MICROSTD_PREVIEW");
+    println!("cargo:rerun-if-env-changed=EROC_MICROSTD_PREVIEW_LINES");
+    println!("cargo:rerun-if-env-changed=EROC_MICROSTD_REMOVALS");
 }
@@ -18,8 +18,7 @@
 
 use std::path;
 
-mod importer;
-use importer::*;
+use crate::importer::*;
 
 /// Imports and alters [`core::error`] module.
 pub fn import(src_path: &path::Path, dst_path: &path::Path) {
@@ -52,11 +51,14 @@ pub fn import(src_path: &path::Path, dst_path: &path::Path) {
     let f = remove_block(f, r"impl Error for crate::char::ParseCharError"); // Unstable feature.
     let f = remove_block(f, r"impl Error for crate::ffi::FromBytesWithNulError"); // Unstable feature.
     let f = remove_fn(f, "provide"); // It is unstable feature of `Error` trait.
-    let f = remove_text(f, "Demand, Provider, "); // Unused and unstable.
+    let f = remove_literal(f, "Demand, Provider, "); // Unused and unstable.
 
-    // Removes tests.
-    let f = remove_attr(f, r"cfg\(test\)");
+    // Removes tests. `cfg(test)` attribute is only removed if it annotates the test module.
+    let f = remove_line_before(f, r"^\s*#\[cfg\(test\)\]", r"^\s*mod tests\b", 1);
     let f = remove_line(f, "mod tests");
 
+    // Fails if most of the file has been removed by mistake.
+    let f = assert_min_lines(f, src_path, 0.25);
+
     write_file(f, dst_path);
 }
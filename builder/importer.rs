@@ -18,7 +18,7 @@
 
 #![allow(unused)]
 
-use std::{fs, io, path};
+use std::{cell::RefCell, collections::VecDeque, fs, io, mem, path, rc::Rc};
 
 // =================================================================================================
 // Built-in convenient transformers
@@ -35,6 +35,27 @@ pub fn write_file<T: Transformer>(mut inner: T, p: &path::Path) {
     inner.write_to(&mut io::BufWriter::new(fs::File::create(p).unwrap()));
 }
 
+/// Creates a transformer to panic if fewer than `min_ratio` of the lines in the source file
+/// are left at the end of the transformation.
+///
+/// This guards against a rule that accidentally removes most of the file,
+/// e.g. a block match that never finds its end.
+pub fn assert_min_lines<T: Transformer>(
+    inner: T,
+    src_path: &path::Path,
+    min_ratio: f64,
+) -> MinLines<T> {
+    return MinLines::new(inner, src_path, min_ratio);
+}
+
+/// Creates a transformer to panic with `message` if any line matches the regex rule.
+///
+/// This guards against a source file that has changed incompatibly,
+/// e.g. a known sentinel that the other rules can't handle.
+pub fn fail_on_line<T: Transformer>(inner: T, pattern: &str, message: &str) -> FailOnLine<T> {
+    return FailOnLine::new(inner, pattern, message);
+}
+
 /// Creates a transformer to remove `stable` attribute.
 pub fn remove_stable_attr<T: Transformer>(inner: T) -> BlockRegex<T> {
     return BlockRegex::new(inner, None, r##"^\s*#!?\[stable\(.*"##, None, &[]);
@@ -54,6 +75,12 @@ pub fn remove_attr<T: Transformer>(inner: T, re: &str) -> BlockRegex<BlockRegex<
     return f;
 }
 
+/// Creates a transformer to remove lang item attributes, e.g. `#[lang = "..."]`
+/// or `#[cfg_attr(not(test), lang = "...")]`, and keep the items they annotate.
+pub fn remove_lang_items<T: Transformer>(inner: T) -> BlockRegex<BlockRegex<T>> {
+    return remove_attr(inner, r##"\blang\s*="##);
+}
+
 /// Creates a transformer to remove blocks of code that contain `unstable` attribute.
 pub fn remove_unstable_features<T: Transformer>(inner: T) -> BlockRegex<T> {
     return BlockRegex::new(
@@ -92,6 +119,24 @@ pub fn remove_block<T: Transformer>(inner: T, name: &str) -> BlockRegex<T> {
     );
 }
 
+/// Creates a transformer to add `#[cfg(...)]` attribute with the specified predicate
+/// to the functions and types with the specified names, as well as the implementations
+/// of those types.
+pub fn add_cfg_attr<T: Transformer>(inner: T, predicate: &str, names: &[&str]) -> BlockRegex<T> {
+    let names = names.join("|");
+
+    return BlockRegex::new(
+        inner,
+        None,
+        &format!(
+            r"^(\s*)(?:(?:pub(?:\(crate\))? )?(?:unsafe )?(?:fn|struct|enum|trait) (?:{0})\b|impl\b.*\b(?:{0})\b.*\{{).*",
+            names
+        ),
+        None,
+        &[&format!("${{1}}#[cfg({})]", predicate), "$0"],
+    );
+}
+
 /// Creates a transformer to remove lines that match the specified regex rule.
 pub fn remove_line<T: Transformer>(inner: T, text: &str) -> BlockRegex<T> {
     return BlockRegex::new(inner, None, text, None, &[]);
@@ -107,12 +152,98 @@ pub fn replace_text<T: Transformer>(inner: T, before: &str, after: &str) -> Bloc
     return BlockRegex::new(inner, None, before, None, &[after]);
 }
 
+/// Creates a transformer to remove all occurrences of the literal text `text`.
+///
+/// Panics at the end of file if `text` doesn't occur at all.
+pub fn remove_literal<T: Transformer>(inner: T, text: &str) -> BlockRegex<T> {
+    return replace_literal(inner, text, "");
+}
+
+/// Creates a transformer to replace all occurrences of the literal text `before` by `after`.
+///
+/// Unlike [`replace_text`], `before` is not a regex rule and `after` is inserted as is.
+/// Panics at the end of file if `before` doesn't occur at all, so that a rule which no longer
+/// applies to a changed source file doesn't silently do nothing.
+pub fn replace_literal<T: Transformer>(inner: T, before: &str, after: &str) -> BlockRegex<T> {
+    return BlockRegex::new(
+        inner,
+        None,
+        &regex::escape(before),
+        None,
+        &[&after.replace('$', "$$")],
+    )
+    .expect_match(&format!("Literal `{}` to be replaced is not found.", before));
+}
+
+/// Creates a transformer to replace text that matches the specified regex rule
+/// within a sliding window of `window` consecutive lines.
+pub fn replace_window<T: Transformer>(
+    inner: T,
+    window: usize,
+    before: &str,
+    after: &str,
+) -> WindowRegex<T> {
+    return WindowRegex::new(inner, window, before, after);
+}
+
+/// Creates a transformer to remove lines that match the regex rule `text`
+/// only if one of the next `window` lines matches the regex rule `next_text`.
+pub fn remove_line_before<T: Transformer>(
+    inner: T,
+    text: &str,
+    next_text: &str,
+    window: usize,
+) -> LookAhead<T> {
+    return LookAhead::new(inner, text, next_text, window, &[]);
+}
+
 /// Creates a transformer to insert the specified block to text
 /// to the beginning the file right after the module documentation.
 pub fn insert_to_beginning<T: Transformer>(inner: T, text: &[&str]) -> InsertToBeginning<T> {
     return InsertToBeginning::new(inner, text);
 }
 
+/// Creates a transformer to replace the declaration of the module with the specified name
+/// by the inline module whose content is generated by the specified transformer.
+pub fn inline_module<T: Transformer, U: Transformer>(
+    inner: T,
+    name: &str,
+    module: U,
+) -> InlineModule<T, U> {
+    return InlineModule::new(inner, name, module);
+}
+
+/// Creates a transformer to convert the single-rule declarative macros 2.0,
+/// e.g. `pub(crate) macro foo($x:expr) { ... }`, to the equivalent `macro_rules!`.
+pub fn decl_macro_to_macro_rules<T: Transformer>(inner: T) -> MacroRules<T> {
+    return MacroRules::new(inner);
+}
+
+/// Creates a transformer to rename the module with the specified name
+/// in both its declaration and the paths that refer to it.
+pub fn rename_module<T: Transformer>(inner: T, old: &str, new: &str) -> RenameModule<T> {
+    return RenameModule::new(inner, old, new);
+}
+
+/// Creates a transformer to apply the sub-pipeline created by `f` only to the lines
+/// between a line that matches `start_marker` and the next line that matches `end_marker`.
+///
+/// The marker lines themselves and all the lines outside the regions are passed through
+/// untouched. `f` is called once for each region with a reader of the lines in the region.
+pub fn within_region<T, U, F>(
+    inner: T,
+    start_marker: &str,
+    end_marker: &str,
+    f: F,
+) -> WithinRegion<T, F>
+where
+    T: Transformer,
+    U: Transformer,
+    F: FnMut(io::Cursor<Vec<u8>>) -> U,
+{
+    return WithinRegion::new(inner, start_marker, end_marker, f);
+}
+
 // =================================================================================================
 // Transformer
 // =================================================================================================
@@ -130,15 +261,24 @@ pub trait Transformer {
     /// returns an empty [`Vec`].
     fn next_lines(&mut self) -> Option<Vec<String>>;
 
+    /// Passes the transformer to `f` to create the next stage of the pipeline.
+    ///
+    /// This allows a pipeline to be read from left to right, e.g.
+    /// `f.then(|f| remove_line(f, "x")).then(remove_doc_attr)`.
+    fn then<U, F>(self, f: F) -> U
+    where
+        Self: Sized,
+        U: Transformer,
+        F: FnOnce(Self) -> U,
+    {
+        return f(self);
+    }
+
     /// Writes the final result to the specified [`Write`](std::io::Write) object.
     fn write_to<F: io::Write>(&mut self, f: &mut F) {
-        loop {
-            if let Some(lines) = self.next_lines() {
-                for line in lines {
-                    f.write(line.as_bytes()).unwrap();
-                }
-            } else {
-                break;
+        while let Some(lines) = self.next_lines() {
+            for line in lines {
+                f.write_all(line.as_bytes()).unwrap();
             }
         }
     }
@@ -184,6 +324,12 @@ impl<F: io::BufRead> Transformer for F {
 ///
 /// [`replace`] defines the list of lines to replace the match. It can contain regex group
 /// from the [`commit_re`] line match.
+///
+/// If the transformer is labelled using [`BlockRegex::with_label`], each block of lines
+/// it removes is recorded to the [`RemovalLog`] together with the label.
+///
+/// If the transformer is created with [`BlockRegex::expect_match`], it panics at the end of file
+/// if [`commit_re`] has never matched.
 pub struct BlockRegex<T: Transformer> {
     inner: T,
     start_re: Option<regex::Regex>,
@@ -194,6 +340,29 @@ pub struct BlockRegex<T: Transformer> {
     state: BlockRegexState,
     keep_lines: Vec<String>,
     prefix: String,
+
+    label: Option<(String, RemovalLog)>,
+    line_no: usize,
+    removal: Option<Removal>,
+
+    expect_match: Option<String>,
+    matched: bool,
+}
+
+/// The record of the blocks of lines removed by labelled transformers.
+pub type RemovalLog = Rc<RefCell<Vec<Removal>>>;
+
+/// A block of consecutive lines removed by a labelled transformer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Removal {
+    /// The label of the transformer that removed the lines.
+    pub label: String,
+
+    /// The line number (1-based) of the first removed line in the input of the transformer.
+    pub first_line: usize,
+
+    /// The removed lines.
+    pub lines: Vec<String>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -223,8 +392,50 @@ impl<T: Transformer> BlockRegex<T> {
             state: BlockRegexState::Ready,
             keep_lines: Vec::<String>::new(),
             prefix: String::new(),
+
+            label: None,
+            line_no: 0,
+            removal: None,
+
+            expect_match: None,
+            matched: false,
         };
     }
+
+    /// Makes the transformer panic with `message` at the end of file if no line has matched.
+    pub fn expect_match(mut self, message: &str) -> Self {
+        self.expect_match = Some(message.to_string());
+        return self;
+    }
+
+    /// Labels the transformer so that the lines it removes are recorded to `log`.
+    pub fn with_label(mut self, label: &str, log: &RemovalLog) -> Self {
+        self.label = Some((label.to_string(), log.clone()));
+        return self;
+    }
+
+    /// Starts recording a removed block if the transformer is labelled.
+    fn begin_removal(&mut self, lines: &[String], line: &str) {
+        if let Some((label, _)) = &self.label {
+            if self.replace.is_empty() {
+                let mut removed = lines.to_vec();
+                removed.push(line.to_string());
+
+                self.removal = Some(Removal {
+                    label: label.clone(),
+                    first_line: self.line_no - lines.len(),
+                    lines: removed,
+                });
+            }
+        }
+    }
+
+    /// Finishes recording the current removed block.
+    fn end_removal(&mut self) {
+        if let Some(removal) = self.removal.take() {
+            self.label.as_ref().unwrap().1.borrow_mut().push(removal);
+        }
+    }
 }
 
 // Implement `Transformer` trait -------------------------------------------------------------------
@@ -235,12 +446,16 @@ impl<T: Transformer> Transformer for BlockRegex<T> {
 
         if let Some(src_lines) = self.inner.next_lines() {
             for line in &src_lines {
+                self.line_no += 1;
+
                 match self.state {
                     BlockRegexState::Ready => {
                         let match_start = match_opt_regex(&self.start_re, line);
                         let match_commit = self.commit_re.captures(line);
 
                         if let Some(cap) = &match_commit {
+                            self.matched = true;
+
                             for replace in &self.replace {
                                 dst_lines.push(self.commit_re.replace(line, replace).to_string());
                             }
@@ -249,6 +464,8 @@ impl<T: Transformer> Transformer for BlockRegex<T> {
                                 self.state = BlockRegexState::Committed;
                                 self.prefix = cap[1].to_string();
                             }
+
+                            self.begin_removal(&[], line);
                         } else if match_start {
                             self.state = BlockRegexState::Started;
                             assert!(self.keep_lines.is_empty());
@@ -263,6 +480,8 @@ impl<T: Transformer> Transformer for BlockRegex<T> {
                         let match_commit = self.commit_re.captures(line);
 
                         if let Some(cap) = &match_commit {
+                            self.matched = true;
+
                             if self.end_re.is_some() {
                                 self.state = BlockRegexState::Committed;
                                 self.prefix = cap[1].to_string();
@@ -270,7 +489,8 @@ impl<T: Transformer> Transformer for BlockRegex<T> {
                                 self.state = BlockRegexState::Ready;
                             }
 
-                            self.keep_lines.clear();
+                            let keep_lines = mem::take(&mut self.keep_lines);
+                            self.begin_removal(&keep_lines, line);
 
                             for replace in &self.replace {
                                 dst_lines.push(self.commit_re.replace(line, replace).to_string());
@@ -285,6 +505,10 @@ impl<T: Transformer> Transformer for BlockRegex<T> {
                     }
 
                     BlockRegexState::Committed => {
+                        if let Some(removal) = &mut self.removal {
+                            removal.lines.push(line.clone());
+                        }
+
                         if line.starts_with(&self.prefix) {
                             let truncated = &line[self.prefix.len()..];
                             let match_end = match_opt_regex(&self.end_re, truncated);
@@ -295,17 +519,187 @@ impl<T: Transformer> Transformer for BlockRegex<T> {
                         }
                     }
                 }
+
+                if self.state != BlockRegexState::Committed {
+                    self.end_removal();
+                }
             }
 
             return Some(dst_lines);
+        } else if let Some(message) = self.expect_match.as_ref().filter(|_| !self.matched) {
+            panic!("{}", message);
+        } else if self.keep_lines.is_empty() {
+            return None;
         } else {
-            if self.keep_lines.is_empty() {
-                return None;
-            } else {
-                assert_eq!(self.state, BlockRegexState::Started);
-                dst_lines.append(&mut self.keep_lines);
-                return Some(dst_lines);
+            assert_eq!(self.state, BlockRegexState::Started);
+            dst_lines.append(&mut self.keep_lines);
+            return Some(dst_lines);
+        }
+    }
+}
+
+// =================================================================================================
+// Search and replace over a sliding window of lines using regular expression
+// =================================================================================================
+
+/// A text processor that searches and replaces text across multiple consecutive lines
+/// using regular expression.
+///
+/// The regular expression is tested against the concatenation of the current window of lines,
+/// including the line breaks, so that it can match a construct that spans more than one line,
+/// e.g. an attribute and the item it annotates.
+///
+/// If there is a match, the whole window is replaced by the result, which can contain
+/// a different number of lines, and the next window starts after it.
+/// Otherwise the window slides one line forward.
+pub struct WindowRegex<T: Transformer> {
+    inner: T,
+    window: usize,
+    re: regex::Regex,
+    replace: String,
+
+    keep_lines: VecDeque<String>,
+}
+
+// Constructors ------------------------------------------------------------------------------------
+
+impl<T: Transformer> WindowRegex<T> {
+    pub fn new(inner: T, window: usize, re: &str, replace: &str) -> Self {
+        assert!(window > 0);
+
+        return Self {
+            inner,
+            window,
+            re: regex::Regex::new(re).unwrap(),
+            replace: replace.to_string(),
+
+            keep_lines: VecDeque::<String>::with_capacity(window),
+        };
+    }
+
+    /// Replaces the text in the current window if there is a match.
+    fn replace_window(&mut self, dst_lines: &mut Vec<String>) -> bool {
+        let text = self.keep_lines.iter().map(String::as_str).collect::<String>();
+
+        if self.re.is_match(&text) {
+            let replaced = self.re.replace(&text, self.replace.as_str());
+
+            dst_lines.extend(replaced.split_inclusive('\n').map(str::to_string));
+            self.keep_lines.clear();
+
+            return true;
+        }
+
+        return false;
+    }
+}
+
+// Implement `Transformer` trait -------------------------------------------------------------------
+
+impl<T: Transformer> Transformer for WindowRegex<T> {
+    fn next_lines(&mut self) -> Option<Vec<String>> {
+        let mut dst_lines = Vec::<String>::new();
+
+        if let Some(src_lines) = self.inner.next_lines() {
+            for line in src_lines {
+                self.keep_lines.push_back(line);
+
+                if self.keep_lines.len() == self.window && !self.replace_window(&mut dst_lines) {
+                    dst_lines.push(self.keep_lines.pop_front().unwrap());
+                }
+            }
+
+            return Some(dst_lines);
+        } else if self.keep_lines.is_empty() {
+            return None;
+        } else {
+            // The last lines are shorter than the window but can still contain a match.
+            if !self.replace_window(&mut dst_lines) {
+                dst_lines.extend(self.keep_lines.drain(..));
+            }
+
+            return Some(dst_lines);
+        }
+    }
+}
+
+// =================================================================================================
+// Search and replace with look-ahead using regular expression
+// =================================================================================================
+
+/// A text processor that replaces the lines that match a regular expression,
+/// but only if one of the following lines matches another regular expression.
+///
+/// Up to `window` lines are kept back so that the decision about a line can be made
+/// once the lines after it are known. At the end of file, the last lines are decided
+/// with the fewer lines that follow them.
+pub struct LookAhead<T: Transformer> {
+    inner: T,
+    re: regex::Regex,
+    next_re: regex::Regex,
+    window: usize,
+    replace: Vec<String>,
+
+    keep_lines: VecDeque<String>,
+}
+
+// Constructors ------------------------------------------------------------------------------------
+
+impl<T: Transformer> LookAhead<T> {
+    pub fn new(inner: T, re: &str, next_re: &str, window: usize, replace: &[&str]) -> Self {
+        assert!(window > 0);
+
+        return Self {
+            inner,
+            re: regex::Regex::new(re).unwrap(),
+            next_re: regex::Regex::new(next_re).unwrap(),
+            window,
+            replace: arr_str_to_vec_string(replace),
+
+            keep_lines: VecDeque::<String>::with_capacity(window + 1),
+        };
+    }
+
+    /// Makes the decision about the first kept line and moves the result to `dst_lines`.
+    fn commit_first(&mut self, dst_lines: &mut Vec<String>) {
+        let line = self.keep_lines.pop_front().unwrap();
+
+        let is_followed = self.keep_lines.iter().any(|next| self.next_re.is_match(next));
+
+        if is_followed && self.re.is_match(&line) {
+            for entry in &self.replace {
+                dst_lines.push(self.re.replace(&line, entry.as_str()).to_string());
+            }
+        } else {
+            dst_lines.push(line);
+        }
+    }
+}
+
+// Implement `Transformer` trait -------------------------------------------------------------------
+
+impl<T: Transformer> Transformer for LookAhead<T> {
+    fn next_lines(&mut self) -> Option<Vec<String>> {
+        let mut dst_lines = Vec::<String>::new();
+
+        if let Some(src_lines) = self.inner.next_lines() {
+            for line in src_lines {
+                self.keep_lines.push_back(line);
+
+                if self.keep_lines.len() > self.window {
+                    self.commit_first(&mut dst_lines);
+                }
+            }
+
+            return Some(dst_lines);
+        } else if self.keep_lines.is_empty() {
+            return None;
+        } else {
+            while !self.keep_lines.is_empty() {
+                self.commit_first(&mut dst_lines);
             }
+
+            return Some(dst_lines);
         }
     }
 }
@@ -347,7 +741,7 @@ impl<T: Transformer> Transformer for InsertToBeginning<T> {
                 let mut dst_lines = Vec::<String>::with_capacity(src_lines.len() + self.text.len());
 
                 for line in &src_lines {
-                    if !self.done && !self.doc_re.is_match(&line) {
+                    if !self.done && !self.doc_re.is_match(line) {
                         // Only inserts the text after the module documentation.
                         for new_line in &self.text {
                             dst_lines.push(format!("{}\n", new_line));
@@ -367,10 +761,432 @@ impl<T: Transformer> Transformer for InsertToBeginning<T> {
     }
 }
 
+// =================================================================================================
+// Inline module
+// =================================================================================================
+
+/// Replaces the declaration of an out-of-line module, e.g. `mod foo;`, by the inline module
+/// `mod foo { ... }` whose content is generated by another transformer.
+///
+/// This allows a module and its submodules to be generated in a single file.
+/// Out-of-line modules declared in the included file would otherwise be loaded
+/// from the directory of the generated file.
+pub struct InlineModule<T: Transformer, U: Transformer> {
+    inner: T,
+    module: Option<U>,
+
+    decl_re: regex::Regex,
+}
+
+// Constructors ------------------------------------------------------------------------------------
+
+impl<T: Transformer, U: Transformer> InlineModule<T, U> {
+    pub fn new(inner: T, name: &str, module: U) -> Self {
+        return Self {
+            inner,
+            module: Some(module),
+
+            decl_re: regex::Regex::new(&format!(
+                r"^(\s*)((?:pub(?:\(.*\))?\s+)?mod\s+{}\s*);",
+                regex::escape(name)
+            ))
+            .unwrap(),
+        };
+    }
+}
+
+// Implement `Transformer` trait -------------------------------------------------------------------
+
+impl<T: Transformer, U: Transformer> Transformer for InlineModule<T, U> {
+    fn next_lines(&mut self) -> Option<Vec<String>> {
+        if let Some(src_lines) = self.inner.next_lines() {
+            let mut dst_lines = Vec::<String>::with_capacity(src_lines.len());
+
+            for line in src_lines {
+                if let Some(cap) = self.decl_re.captures(&line) {
+                    let module = self.module.as_mut().expect("Module is declared more than once.");
+
+                    dst_lines.push(format!("{}{} {{\n", &cap[1], &cap[2]));
+
+                    while let Some(mut lines) = module.next_lines() {
+                        dst_lines.append(&mut lines);
+                    }
+
+                    if let Some(last) = dst_lines.last_mut() {
+                        if !last.ends_with('\n') {
+                            last.push('\n');
+                        }
+                    }
+
+                    dst_lines.push(format!("{}}}\n", &cap[1]));
+                    self.module = None;
+                } else {
+                    dst_lines.push(line);
+                }
+            }
+
+            return Some(dst_lines);
+        }
+
+        return None;
+    }
+}
+
+// =================================================================================================
+// Rename module
+// =================================================================================================
+
+/// Renames a module consistently in its declaration, e.g. `mod foo;` or `mod foo {`,
+/// and in every path that refers to it, e.g. `use self::foo::Bar;` or `foo::bar()`.
+pub struct RenameModule<T: Transformer> {
+    inner: T,
+
+    decl_re: regex::Regex,
+    decl_replace: String,
+    path_re: regex::Regex,
+    path_replace: String,
+}
+
+// Constructors ------------------------------------------------------------------------------------
+
+impl<T: Transformer> RenameModule<T> {
+    pub fn new(inner: T, old: &str, new: &str) -> Self {
+        let old = regex::escape(old);
+
+        return Self {
+            inner,
+
+            decl_re: regex::Regex::new(&format!(r"\b(mod\s+){}\b", old)).unwrap(),
+            decl_replace: format!("${{1}}{}", new),
+            path_re: regex::Regex::new(&format!(r"\b{}::", old)).unwrap(),
+            path_replace: format!("{}::", new),
+        };
+    }
+}
+
+// Implement `Transformer` trait -------------------------------------------------------------------
+
+impl<T: Transformer> Transformer for RenameModule<T> {
+    fn next_lines(&mut self) -> Option<Vec<String>> {
+        if let Some(src_lines) = self.inner.next_lines() {
+            let mut dst_lines = Vec::<String>::with_capacity(src_lines.len());
+
+            for line in src_lines {
+                let line = self.decl_re.replace_all(&line, self.decl_replace.as_str());
+                let line = self.path_re.replace_all(&line, self.path_replace.as_str());
+
+                dst_lines.push(line.into_owned());
+            }
+
+            return Some(dst_lines);
+        }
+
+        return None;
+    }
+}
+
+// =================================================================================================
+// Convert declarative macros 2.0 to macro_rules!
+// =================================================================================================
+
+/// Converts each declarative macro 2.0 that has a single rule to `macro_rules!`,
+/// which is the only stable form of declarative macros.
+///
+/// ```text
+/// pub(crate) macro foo($x:expr) {        macro_rules! foo {
+///     bar($x)                                ($x:expr) => {
+/// }                                              bar($x)
+///                                            };
+///                                        }
+///
+///                                        pub(crate) use foo;
+/// ```
+///
+/// The visibility of the macro is kept by re-exporting it, which also allows the macro
+/// to be used by path and before its definition like a declarative macro 2.0.
+pub struct MacroRules<T: Transformer> {
+    inner: T,
+
+    decl_re: regex::Regex,
+    macro_end: Option<MacroRulesEnd>,
+}
+
+/// The information to finish the macro currently being converted.
+struct MacroRulesEnd {
+    end_line: String,
+    prefix: String,
+    vis: String,
+    name: String,
+}
+
+// Constructors ------------------------------------------------------------------------------------
+
+impl<T: Transformer> MacroRules<T> {
+    pub fn new(inner: T) -> Self {
+        return Self {
+            inner,
+
+            decl_re: regex::Regex::new(
+                r"^(\s*)((?:pub(?:\(.*\))?\s+)?)macro\s+(\w+)\s*\((.*)\)\s*\{\s*$",
+            )
+            .unwrap(),
+            macro_end: None,
+        };
+    }
+}
+
+// Implement `Transformer` trait -------------------------------------------------------------------
+
+impl<T: Transformer> Transformer for MacroRules<T> {
+    fn next_lines(&mut self) -> Option<Vec<String>> {
+        if let Some(src_lines) = self.inner.next_lines() {
+            let mut dst_lines = Vec::<String>::with_capacity(src_lines.len());
+
+            for line in src_lines {
+                if let Some(end) = &self.macro_end {
+                    if line.trim_end() == end.end_line {
+                        dst_lines.push(format!("{}    }};\n", end.prefix));
+                        dst_lines.push(format!("{}}}\n", end.prefix));
+
+                        if !end.vis.is_empty() {
+                            dst_lines.push("\n".to_string());
+                            dst_lines.push(format!("{}{}use {};\n", end.prefix, end.vis, end.name));
+                        }
+
+                        self.macro_end = None;
+                    } else if line.trim().is_empty() {
+                        dst_lines.push(line);
+                    } else {
+                        dst_lines.push(format!("    {}", line));
+                    }
+                } else if let Some(cap) = self.decl_re.captures(&line) {
+                    dst_lines.push(format!("{}macro_rules! {} {{\n", &cap[1], &cap[3]));
+                    dst_lines.push(format!("{}    ({}) => {{\n", &cap[1], &cap[4]));
+
+                    self.macro_end = Some(MacroRulesEnd {
+                        end_line: format!("{}}}", &cap[1]),
+                        prefix: cap[1].to_string(),
+                        vis: cap[2].to_string(),
+                        name: cap[3].to_string(),
+                    });
+                } else {
+                    dst_lines.push(line);
+                }
+            }
+
+            return Some(dst_lines);
+        }
+
+        return None;
+    }
+}
+
+// =================================================================================================
+// Minimum number of lines
+// =================================================================================================
+
+/// Counts the lines produced by another transformer and panics at the end of file
+/// if there are fewer than the specified fraction of the lines in the source file.
+pub struct MinLines<T: Transformer> {
+    inner: T,
+    src_name: String,
+    src_lines: usize,
+    min_ratio: f64,
+
+    dst_lines: usize,
+}
+
+// Constructors ------------------------------------------------------------------------------------
+
+impl<T: Transformer> MinLines<T> {
+    pub fn new(inner: T, src_path: &path::Path, min_ratio: f64) -> Self {
+        return Self {
+            inner,
+            src_name: src_path.display().to_string(),
+            src_lines: fs::read_to_string(src_path).unwrap().lines().count(),
+            min_ratio,
+
+            dst_lines: 0,
+        };
+    }
+}
+
+// Implement `Transformer` trait -------------------------------------------------------------------
+
+impl<T: Transformer> Transformer for MinLines<T> {
+    fn next_lines(&mut self) -> Option<Vec<String>> {
+        if let Some(lines) = self.inner.next_lines() {
+            self.dst_lines += lines.iter().map(|line| line.matches('\n').count()).sum::<usize>();
+            return Some(lines);
+        }
+
+        let min_lines = (self.src_lines as f64 * self.min_ratio).ceil() as usize;
+
+        if self.dst_lines < min_lines {
+            panic!(
+                "{}: only {} of {} lines are left after transformation, expected at least {}.",
+                self.src_name, self.dst_lines, self.src_lines, min_lines
+            );
+        }
+
+        return None;
+    }
+}
+
+// =================================================================================================
+// Fail on matching line
+// =================================================================================================
+
+/// Passes the lines produced by another transformer through unchanged
+/// and panics as soon as one of them matches the regex rule.
+pub struct FailOnLine<T: Transformer> {
+    inner: T,
+    re: regex::Regex,
+    message: String,
+}
+
+// Constructors ------------------------------------------------------------------------------------
+
+impl<T: Transformer> FailOnLine<T> {
+    pub fn new(inner: T, pattern: &str, message: &str) -> Self {
+        return Self {
+            inner,
+            re: regex::Regex::new(pattern).unwrap(),
+            message: message.to_string(),
+        };
+    }
+}
+
+// Implement `Transformer` trait -------------------------------------------------------------------
+
+impl<T: Transformer> Transformer for FailOnLine<T> {
+    fn next_lines(&mut self) -> Option<Vec<String>> {
+        if let Some(lines) = self.inner.next_lines() {
+            if let Some(line) = lines.iter().find(|line| self.re.is_match(line)) {
+                panic!("{} Matching line: {}", self.message, line.trim_end());
+            }
+
+            return Some(lines);
+        }
+
+        return None;
+    }
+}
+
+// =================================================================================================
+// Transform only within a region
+// =================================================================================================
+
+/// Collects the lines in each region delimited by the start and end markers
+/// and passes them through a sub-pipeline, leaving the other lines unchanged.
+pub struct WithinRegion<T: Transformer, F> {
+    inner: T,
+    f: F,
+
+    start_re: regex::Regex,
+    end_re: regex::Regex,
+
+    region: Option<String>,
+}
+
+// Constructors ------------------------------------------------------------------------------------
+
+impl<T: Transformer, F> WithinRegion<T, F> {
+    pub fn new(inner: T, start_marker: &str, end_marker: &str, f: F) -> Self {
+        return Self {
+            inner,
+            f,
+
+            start_re: regex::Regex::new(start_marker).unwrap(),
+            end_re: regex::Regex::new(end_marker).unwrap(),
+
+            region: None,
+        };
+    }
+}
+
+// Implement `Transformer` trait -------------------------------------------------------------------
+
+impl<T, U, F> Transformer for WithinRegion<T, F>
+where
+    T: Transformer,
+    U: Transformer,
+    F: FnMut(io::Cursor<Vec<u8>>) -> U,
+{
+    fn next_lines(&mut self) -> Option<Vec<String>> {
+        if let Some(src_lines) = self.inner.next_lines() {
+            let mut dst_lines = Vec::<String>::with_capacity(src_lines.len());
+
+            for line in src_lines {
+                if let Some(region) = self.region.as_mut() {
+                    if self.end_re.is_match(&line) {
+                        let mut sub = (self.f)(io::Cursor::new(mem::take(region).into_bytes()));
+
+                        while let Some(mut lines) = sub.next_lines() {
+                            dst_lines.append(&mut lines);
+                        }
+
+                        dst_lines.push(line);
+                        self.region = None;
+                    } else {
+                        region.push_str(&line);
+                    }
+                } else {
+                    if self.start_re.is_match(&line) {
+                        self.region = Some(String::new());
+                    }
+
+                    dst_lines.push(line);
+                }
+            }
+
+            return Some(dst_lines);
+        }
+
+        assert!(self.region.is_none(), "Region is not closed before the end of file.");
+
+        return None;
+    }
+}
+
 // =================================================================================================
 // Utilities
 // =================================================================================================
 
+/// Returns the first `max_lines` lines of the file for inspection.
+///
+/// If the file is longer, the last line tells how many lines are omitted.
+pub fn preview_file(p: &path::Path, max_lines: usize) -> Vec<String> {
+    let content = fs::read_to_string(p).unwrap();
+    let mut lines = content.lines().take(max_lines).map(String::from).collect::<Vec<String>>();
+
+    let num_lines = content.lines().count();
+
+    if num_lines > max_lines {
+        lines.push(format!("... ({} more lines)", num_lines - max_lines));
+    }
+
+    return lines;
+}
+
+/// Describes each block of lines recorded in the removal log, in the order of removal.
+///
+/// The line numbers refer to the input of the transformer that removed the lines,
+/// which is the output of the previous stages of the pipeline.
+pub fn report_removals(log: &RemovalLog) -> Vec<String> {
+    return log
+        .borrow()
+        .iter()
+        .map(|removal| {
+            let last_line = removal.first_line + removal.lines.len() - 1;
+            return format!(
+                "{}: removed lines {}-{}",
+                removal.label, removal.first_line, last_line
+            );
+        })
+        .collect();
+}
+
 /// Converts the specified optional string reference to an optional regular expression object.
 fn opt_str_to_regex(value: Option<&str>) -> Option<regex::Regex> {
     if let Some(pattern) = value {
@@ -401,3 +1217,524 @@ fn match_opt_regex(opt_pattern: &Option<regex::Regex>, text: &str) -> bool {
 
     return false;
 }
+
+// =================================================================================================
+// Tests
+// =================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Creates a transformer that reads the specified text.
+    fn source(text: &str) -> io::Cursor<Vec<u8>> {
+        return io::Cursor::new(text.as_bytes().to_vec());
+    }
+
+    /// Runs the transformer to the end and returns the generated text.
+    fn run<T: Transformer>(mut f: T) -> String {
+        let mut output = Vec::<u8>::new();
+        f.write_to(&mut output);
+
+        return String::from_utf8(output).unwrap();
+    }
+
+    // Rename module -------------------------------------------------------------------------------
+
+    #[test]
+    fn rename_module_declaration_and_paths() {
+        let f = source(concat!(
+            "mod repr_unpacked;\n",
+            "pub(crate) mod repr_unpacked {\n",
+            "use repr_unpacked::Repr;\n",
+            "use self::repr_unpacked::{Repr, Custom};\n",
+            "let repr = super::repr_unpacked::Repr::new();\n",
+        ));
+
+        assert_eq!(
+            run(rename_module(f, "repr_unpacked", "repr")),
+            concat!(
+                "mod repr;\n",
+                "pub(crate) mod repr {\n",
+                "use repr::Repr;\n",
+                "use self::repr::{Repr, Custom};\n",
+                "let repr = super::repr::Repr::new();\n",
+            ),
+        );
+    }
+
+    #[test]
+    fn rename_module_whole_words_only() {
+        let text = concat!(
+            "mod repr_unpacked_ext;\n",
+            "use my_repr_unpacked::Repr;\n",
+            "let repr_unpacked = 1;\n",
+            "// repr_unpacked is renamed in paths only.\n",
+        );
+
+        assert_eq!(run(rename_module(source(text), "repr_unpacked", "repr")), text);
+    }
+
+    // Remove lang items ---------------------------------------------------------------------------
+
+    #[test]
+    fn remove_lang_items_keeps_items() {
+        let f = source(concat!(
+            "#[lang = \"String\"]\n",
+            "pub struct String;\n",
+            "    #[cfg_attr(not(test), lang = \"owned_box\")]\n",
+            "    pub struct Box<T>(T);\n",
+            "#[derive(Clone)]\n",
+            "#[lang=\"Range\"]\n",
+            "pub struct Range;\n",
+        ));
+
+        assert_eq!(
+            run(remove_lang_items(f)),
+            concat!(
+                "pub struct String;\n",
+                "    pub struct Box<T>(T);\n",
+                "#[derive(Clone)]\n",
+                "pub struct Range;\n",
+            ),
+        );
+    }
+
+    #[test]
+    fn remove_lang_items_ignores_other_attributes() {
+        let text = concat!(
+            "#[language = \"en\"]\n",
+            "#[rustc_lang = \"x\"]\n",
+            "/// #[lang = \"doc\"]\n",
+            "pub struct Text;\n",
+        );
+
+        assert_eq!(run(remove_lang_items(source(text))), text);
+    }
+
+    // Removal log ---------------------------------------------------------------------------------
+
+    #[test]
+    fn removals_attributed_to_stages() {
+        let log = RemovalLog::default();
+
+        let f = source(concat!(
+            "#[stable(feature = \"rust1\")]\n",
+            "pub fn keep() {\n",
+            "}\n",
+            "/// Unstable.\n",
+            "#[unstable(feature = \"x\")]\n",
+            "pub fn drop_me() {\n",
+            "}\n",
+            "pub fn last() {}\n",
+        ));
+        let f = remove_stable_attr(f).with_label("stable", &log);
+        let f = remove_fn(f, "drop_me").with_label("drop_me", &log);
+        let f = replace_text(f, "last", "kept").with_label("replace", &log);
+
+        assert_eq!(run(f), "pub fn keep() {\n}\npub fn kept() {}\n");
+
+        assert_eq!(
+            *log.borrow(),
+            [
+                Removal {
+                    label: "stable".to_string(),
+                    first_line: 1,
+                    lines: vec!["#[stable(feature = \"rust1\")]\n".to_string()],
+                },
+                Removal {
+                    label: "drop_me".to_string(),
+                    first_line: 3,
+                    lines: arr_str_to_vec_string(&[
+                        "/// Unstable.\n",
+                        "#[unstable(feature = \"x\")]\n",
+                        "pub fn drop_me() {\n",
+                        "}\n",
+                    ]),
+                },
+            ],
+        );
+
+        assert_eq!(
+            report_removals(&log),
+            ["stable: removed lines 1-1", "drop_me: removed lines 3-6"],
+        );
+    }
+
+    // Look ahead ----------------------------------------------------------------------------------
+
+    #[test]
+    fn remove_line_before_matching_line() {
+        let f = source(concat!(
+            "#[cfg(test)]\n",
+            "mod tests;\n",
+            "#[cfg(test)]\n",
+            "fn helper() {}\n",
+            "#[cfg(test)]\n",
+        ));
+
+        assert_eq!(
+            run(remove_line_before(f, r"^#\[cfg\(test\)\]", r"^mod tests\b", 1)),
+            concat!("mod tests;\n", "#[cfg(test)]\n", "fn helper() {}\n", "#[cfg(test)]\n"),
+        );
+    }
+
+    #[test]
+    fn remove_line_before_within_window() {
+        let text = concat!("#[inline]\n", "/// Doc.\n", "fn read_buf() {}\n");
+
+        // The matching line is 2 lines after, which is beyond a window of 1 line.
+        let f = remove_line_before(source(text), r"^#\[inline\]", r"fn read_buf\b", 1);
+        assert_eq!(run(f), text);
+
+        let f = remove_line_before(source(text), r"^#\[inline\]", r"fn read_buf\b", 2);
+        assert_eq!(run(f), "/// Doc.\nfn read_buf() {}\n");
+    }
+
+    #[test]
+    fn look_ahead_replaces_line() {
+        let f = source(concat!("#[inline]\n", "fn a() {}\n", "#[inline]\n", "const B: u8 = 0;\n"));
+        let f = LookAhead::new(f, r"^#\[(inline)\]", r"^fn ", 1, &["#[${1}(always)]"]);
+
+        assert_eq!(
+            run(f),
+            concat!("#[inline(always)]\n", "fn a() {}\n", "#[inline]\n", "const B: u8 = 0;\n"),
+        );
+    }
+
+    // Inline module -------------------------------------------------------------------------------
+
+    #[test]
+    fn inline_module_replaces_declaration() {
+        let f =
+            source(concat!("use self::repr::Repr;\n", "    pub(crate) mod repr;\n", "fn f() {}\n"));
+        let module = source(concat!("pub struct Repr;\n", "impl Repr {}"));
+
+        assert_eq!(
+            run(inline_module(f, "repr", module)),
+            concat!(
+                "use self::repr::Repr;\n",
+                "    pub(crate) mod repr {\n",
+                "pub struct Repr;\n",
+                "impl Repr {}\n",
+                "    }\n",
+                "fn f() {}\n",
+            ),
+        );
+    }
+
+    #[test]
+    fn inline_module_ignores_other_modules() {
+        let text = concat!("mod repr_ext;\n", "mod repr { }\n");
+        assert_eq!(run(inline_module(source(text), "repr", source("struct Repr;\n"))), text);
+    }
+
+    #[test]
+    #[should_panic(expected = "Module is declared more than once.")]
+    fn inline_module_declared_twice() {
+        run(inline_module(source("mod repr;\nmod repr;\n"), "repr", source("")));
+    }
+
+    // Window regex --------------------------------------------------------------------------------
+
+    #[test]
+    fn replace_window_across_lines() {
+        let f = source(concat!("#[inline]\n", "fn a() {}\n", "#[inline]\n", "\n", "fn b() {}\n",));
+
+        assert_eq!(
+            run(replace_window(f, 2, r"#\[inline\]\n(fn \w+)", "#[inline(always)]\n$1")),
+            concat!("#[inline(always)]\n", "fn a() {}\n", "#[inline]\n", "\n", "fn b() {}\n"),
+        );
+    }
+
+    #[test]
+    fn replace_window_changes_line_count() {
+        let f = source(concat!("a\n", "b\n", "c\n", "d\n"));
+        assert_eq!(run(replace_window(f, 2, r"b\nc\n", "")), "a\nd\n");
+
+        let f = source(concat!("a\n", "b\n", "c\n"));
+        assert_eq!(run(replace_window(f, 3, r"b\n", "b1\nb2\n")), "a\nb1\nb2\nc\n");
+    }
+
+    #[test]
+    fn replace_window_at_end_of_file() {
+        // The last lines are shorter than the window.
+        let f = source(concat!("a\n", "b\n"));
+        assert_eq!(run(replace_window(f, 5, r"a\nb", "ab")), "ab\n");
+    }
+
+    // Macro rules ---------------------------------------------------------------------------------
+
+    #[test]
+    fn decl_macro_to_macro_rules_keeps_visibility() {
+        let f = source(concat!(
+            "pub(crate) macro foo($x:expr $(,)?) {\n",
+            "    bar($x)\n",
+            "\n",
+            "}\n",
+            "fn after() {}\n",
+        ));
+
+        assert_eq!(
+            run(decl_macro_to_macro_rules(f)),
+            concat!(
+                "macro_rules! foo {\n",
+                "    ($x:expr $(,)?) => {\n",
+                "        bar($x)\n",
+                "\n",
+                "    };\n",
+                "}\n",
+                "\n",
+                "pub(crate) use foo;\n",
+                "fn after() {}\n",
+            ),
+        );
+    }
+
+    #[test]
+    fn decl_macro_to_macro_rules_private_nested() {
+        let f = source(concat!("    macro foo() {\n", "        bar()\n", "    }\n"));
+
+        assert_eq!(
+            run(decl_macro_to_macro_rules(f)),
+            concat!(
+                "    macro_rules! foo {\n",
+                "        () => {\n",
+                "            bar()\n",
+                "        };\n",
+                "    }\n",
+            ),
+        );
+    }
+
+    #[test]
+    fn decl_macro_to_macro_rules_upstream_const_io_error() {
+        let f = source(concat!(
+            "/// Create and return an `io::Error` for a given `ErrorKind` and constant\n",
+            "/// message. This doesn't allocate.\n",
+            "pub(crate) macro const_io_error($kind:expr, $message:expr $(,)?) {\n",
+            "    $crate::io::error::Error::from_static_message({\n",
+            "        const MESSAGE_DATA: $crate::io::error::SimpleMessage =\n",
+            "            $crate::io::error::SimpleMessage::new($kind, $message);\n",
+            "        &MESSAGE_DATA\n",
+            "    })\n",
+            "}\n",
+        ));
+
+        assert_eq!(
+            run(decl_macro_to_macro_rules(f)),
+            concat!(
+                "/// Create and return an `io::Error` for a given `ErrorKind` and constant\n",
+                "/// message. This doesn't allocate.\n",
+                "macro_rules! const_io_error {\n",
+                "    ($kind:expr, $message:expr $(,)?) => {\n",
+                "        $crate::io::error::Error::from_static_message({\n",
+                "            const MESSAGE_DATA: $crate::io::error::SimpleMessage =\n",
+                "                $crate::io::error::SimpleMessage::new($kind, $message);\n",
+                "            &MESSAGE_DATA\n",
+                "        })\n",
+                "    };\n",
+                "}\n",
+                "\n",
+                "pub(crate) use const_io_error;\n",
+            ),
+        );
+    }
+
+    // Minimum number of lines ---------------------------------------------------------------------
+
+    /// Writes `text` to a source file and returns its path.
+    fn source_file(name: &str, text: &str) -> path::PathBuf {
+        let dir = std::env::temp_dir().join("eroc_microstd_importer");
+        fs::create_dir_all(&dir).unwrap();
+
+        let src_path = dir.join(name);
+        fs::write(&src_path, text).unwrap();
+
+        return src_path;
+    }
+
+    /// A source file of 10 lines, 9 of which are a single function.
+    const MOSTLY_ONE_FN: &str = concat!(
+        "fn kept() {}\n",
+        "/// A helper.\n",
+        "fn helper() -> u32 {\n",
+        "    let a = 1;\n",
+        "    let b = 2;\n",
+        "    let c = 3;\n",
+        "    let d = 4;\n",
+        "    a + b + c + d\n",
+        "    // Done.\n",
+        "}\n",
+    );
+
+    #[test]
+    fn assert_min_lines_passes() {
+        let src_path = source_file("min_lines_passes.rs", MOSTLY_ONE_FN);
+
+        let f = read_file(&src_path);
+        let f = remove_line(f, r"^\s*// Done\.");
+        let f = assert_min_lines(f, &src_path, 0.5);
+
+        assert_eq!(run(f).lines().count(), 9);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "only 1 of 10 lines are left after transformation, expected at least 5."
+    )]
+    fn assert_min_lines_fails() {
+        let src_path = source_file("min_lines_fails.rs", MOSTLY_ONE_FN);
+
+        // The rule removes 90% of the lines.
+        let f = read_file(&src_path);
+        let f = remove_fn(f, "helper");
+        let f = assert_min_lines(f, &src_path, 0.5);
+
+        run(f);
+    }
+
+    // Then ----------------------------------------------------------------------------------------
+
+    #[test]
+    fn then_applies_stages_from_left_to_right() {
+        let f = source(concat!("x\n", "a\n"))
+            .then(|f| replace_text(f, "a", "b"))
+            .then(|f| replace_text(f, "b", "c"))
+            .then(|f| remove_line(f, r"^x\s*$"));
+
+        assert_eq!(run(f), "c\n");
+
+        let f =
+            source("a\n").then(|f| replace_text(f, "b", "c")).then(|f| replace_text(f, "a", "b"));
+        assert_eq!(run(f), "b\n");
+    }
+
+    // Add cfg attribute ---------------------------------------------------------------------------
+
+    #[test]
+    fn add_cfg_attr_to_items_and_impls() {
+        let f = source(concat!(
+            "struct Guard<'a> {\n",
+            "impl Drop for Guard<'_> {\n",
+            "    pub(crate) unsafe fn read_to_end(&mut self) {}\n",
+            "    fn read_to_end_fast(&mut self) {}\n",
+            "pub struct Lines<B> {\n",
+            "struct Guardian;\n",
+        ));
+
+        assert_eq!(
+            run(add_cfg_attr(f, r#"feature = "alloc""#, &["Guard", "read_to_end", "Lines"])),
+            concat!(
+                "#[cfg(feature = \"alloc\")]\n",
+                "struct Guard<'a> {\n",
+                "#[cfg(feature = \"alloc\")]\n",
+                "impl Drop for Guard<'_> {\n",
+                "    #[cfg(feature = \"alloc\")]\n",
+                "    pub(crate) unsafe fn read_to_end(&mut self) {}\n",
+                "    fn read_to_end_fast(&mut self) {}\n",
+                "#[cfg(feature = \"alloc\")]\n",
+                "pub struct Lines<B> {\n",
+                "struct Guardian;\n",
+            ),
+        );
+    }
+
+    // Preview file --------------------------------------------------------------------------------
+
+    #[test]
+    fn preview_file_truncates() {
+        let src_path = source_file("preview.rs", "line\nline\nline\n");
+
+        assert_eq!(preview_file(&src_path, 2), ["line", "line", "... (1 more lines)"]);
+        assert_eq!(preview_file(&src_path, 3), ["line", "line", "line"]);
+        assert_eq!(preview_file(&src_path, 0), ["... (3 more lines)"]);
+    }
+
+    // Fail on line --------------------------------------------------------------------------------
+
+    #[test]
+    fn fail_on_line_passes_other_lines() {
+        let f = fail_on_line(source("a\nb\n"), r"^unsafe impl\b", "Unexpected impl.");
+        assert_eq!(run(f), "a\nb\n");
+    }
+
+    #[test]
+    #[should_panic(expected = "Unexpected impl. Matching line: unsafe impl Send for A {}")]
+    fn fail_on_line_panics_on_match() {
+        let f = fail_on_line(
+            source("a\nunsafe impl Send for A {}\n"),
+            r"^unsafe impl\b",
+            "Unexpected impl.",
+        );
+        run(f);
+    }
+
+    // Within region -------------------------------------------------------------------------------
+
+    #[test]
+    fn within_region_transforms_each_region() {
+        let f = source(concat!(
+            "a\n",
+            "// start\n",
+            "a\n",
+            "a\n",
+            "// end\n",
+            "a\n",
+            "// start\n",
+            "a\n",
+            "// end\n",
+        ));
+
+        let mut calls = 0;
+        let f = within_region(f, r"^// start", r"^// end", |f| {
+            calls += 1;
+            return replace_text(f, "a", "b");
+        });
+
+        assert_eq!(
+            run(f),
+            concat!(
+                "a\n",
+                "// start\n",
+                "b\n",
+                "b\n",
+                "// end\n",
+                "a\n",
+                "// start\n",
+                "b\n",
+                "// end\n",
+            ),
+        );
+        assert_eq!(calls, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Region is not closed before the end of file.")]
+    fn within_region_not_closed() {
+        let f = source(concat!("// start\n", "a\n"));
+        run(within_region(f, r"^// start", r"^// end", |f| f));
+    }
+
+    // Literal replacement -------------------------------------------------------------------------
+
+    #[test]
+    fn replace_literal_is_not_regex() {
+        let f = source(concat!("a.b($x)\n", "acb\n", "a.b\n"));
+
+        assert_eq!(run(replace_literal(f, "a.b", "$1")), concat!("$1($x)\n", "acb\n", "$1\n"));
+        assert_eq!(run(remove_literal(source("(os error {code})!\n"), "(os error {code})")), "!\n");
+    }
+
+    #[test]
+    #[should_panic(expected = "Literal `a.b` to be replaced is not found.")]
+    fn replace_literal_not_found() {
+        run(replace_literal(source("acb\n"), "a.b", "x"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Literal `missing` to be replaced is not found.")]
+    fn remove_literal_not_found() {
+        run(remove_literal(source("text\n"), "missing"));
+    }
+}
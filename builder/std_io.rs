@@ -23,26 +23,221 @@
 //!     It requires dynamic memory allocation, which does not always exist in `no_std`.
 //!     For now the library only allows &'static str as the error data of custom error.
 //!     In the future it can use `feature = alloc` to allow dynamic memory allocation.
+//!     `get_ref` and `into_inner` return that string slice instead of the boxed error.
 //!   - [`std::io::Error`] internal data is always packed using `repr_unpacked`.
 //!     `repr_bitpacked` uses a bunch of unstable features which complicates the import process,
 //!     and is rarely used (bare metal software running on 64-bit processor).
+//!   - [`std::io::Error`] doesn't have the error message of OS errors as there is no
+//!     operating system. The description of the error kind is displayed instead.
+//!     For the same reason, `raw_os_error` always returns `None`.
+//!   - Only [`std::io::Read`], [`std::io::Write`], [`std::io::BufRead`] and [`std::io::Seek`]
+//!     traits and [`std::io::Bytes`], [`std::io::Chain`] and [`std::io::Take`] adapters
+//!     are imported from [`std::io`] module itself. Their methods that need dynamic memory allocation
+//!     (e.g. `read_to_end`) are only available with `alloc` feature.
+//!   - Vectored I/O (e.g. `write_vectored`) is imported with [`std::io::IoSlice`] and
+//!     [`std::io::IoSliceMut`] backed by plain slices. Its unstable helpers
+//!     (e.g. `is_write_vectored`) are removed.
+//!   - [`std::io::BufReader`], [`std::io::BufWriter`] and [`std::io::LineWriter`] are not imported
+//!     but reimplemented using statically allocated buffers instead.
+//!   - [`std::io::copy`] is not imported but reimplemented using a buffer on the stack.
+//!     `copy_with_buffer` additionally lets the caller choose the size of the buffer.
 
 use std::path;
 
-mod importer;
-use importer::*;
+use crate::importer::*;
 
 /// Imports and alters [`std::io`] module.
-pub fn import(src_path: &path::Path, dst_path: &path::Path) {
-    import_error(&src_path.join("error.rs"), &dst_path.join("error/mod.rs"));
-    import_error_repr_unpacked(
+///
+/// The blocks of code removed by the major removal rules are recorded to `log`.
+pub fn import(src_path: &path::Path, dst_path: &path::Path, log: &RemovalLog) {
+    import_mod(&src_path.join("mod.rs"), &dst_path.join("mod.rs"), log);
+    import_error(
+        &src_path.join("error.rs"),
         &src_path.join("error/repr_unpacked.rs"),
-        &dst_path.join("error/repr_unpacked.rs"),
+        &dst_path.join("error.rs"),
+        log,
     );
 }
 
+/// The functions and types of [`std::io`] module that need dynamic memory allocation.
+///
+/// They are only available if `alloc` feature is enabled.
+const ALLOC_ITEMS: &[&str] = &[
+    // Helpers
+    "Guard",
+    "append_to_string",
+    "default_read_to_end",
+    "default_read_to_string",
+    "read_until",
+    // Read
+    "read_to_end",
+    "read_to_string",
+    // BufRead
+    "read_line",
+    "split",
+    "lines",
+    "Split",
+    "Lines",
+];
+
+/// Imports and alters [`std::io`] module itself.
+fn import_mod(src_path: &path::Path, dst_path: &path::Path, log: &RemovalLog) {
+    let f = read_file(src_path);
+
+    // Removes attributes that are only allowed in internal/built-in libraries.
+    let f = remove_stable_attr(f);
+    let f = remove_attr(f, "rustc_diagnostic_item");
+    let f = remove_attr(f, "notable_trait");
+
+    // Removes module documentation as inner doc comments are not allowed in the included file.
+    let f = remove_line(f, r"^//!");
+
+    // Removes tests. `cfg(test)` attribute is only removed if it annotates the test module.
+    let f = remove_line_before(f, r"^\s*#\[cfg\(test\)\]", r"^\s*mod tests\b", 1);
+    let f = remove_line(f, "mod tests");
+
+    // Generates `prelude` inline and removes other submodules
+    // as they are declared by the microstd instead.
+    let f = inline_module(f, "prelude", import_prelude(&src_path.with_file_name("prelude.rs")));
+    let f = remove_line(f, r"^(?:pub(?:\(crate\))? )?mod \w+;");
+
+    // Removes imports and re-exports of the items that are not available.
+    let f = remove_line(f, r"^use crate::(?:str|sys_common::memchr);");
+    let f = remove_line(f, r"^use crate::mem::replace;");
+    let f = remove_line(f, r"^const DEFAULT_BUF_SIZE");
+    let f = BlockRegex::new(
+        f,
+        Some(r"^#\[.*"),
+        r"^pub(?:\(crate\))? use (?:self::)?(?:buffered::WriterPanicked|readbuf|stdio)\b.*",
+        None,
+        &[],
+    );
+
+    // Only re-exports the items provided by the microstd.
+    let f = BlockRegex::new(
+        f,
+        None,
+        r"^(\s*)pub use self::\{.*",
+        Some(r"^\};.*"),
+        &[
+            r"pub use self::{",
+            r"    buffered::{BufReader, BufWriter, IntoInnerError, LineWriter},",
+            r"    copy::{copy, copy_with_buffer},",
+            r"    error::{Error, ErrorKind, Result},",
+            r"};",
+        ],
+    );
+
+    // Removes `stable` attributes of the fields of `SeekFrom`.
+    let f = replace_text(f, r"\(#\[stable\(.*\)\] (\w+)\)", "($1)");
+
+    // Removes size hint of reader adapters as it relies on specialization.
+    // `Bytes` iterator falls back to the default size hint.
+    let f = remove_block(f, r"(?:trait |impl.* )SizeHint\b").with_label("io::SizeHint", log);
+    let f = remove_fn(f, "size_hint").with_label("io::size_hint", log);
+
+    // Only keeps functions that need dynamic memory allocation if `alloc` feature is enabled.
+    let f = insert_to_beginning(
+        f,
+        &[
+            r#"#[cfg(feature = "alloc")]"#,
+            r"extern crate alloc;",
+            r"",
+            r#"#[cfg(feature = "alloc")]"#,
+            r"use alloc::{string::String, vec::Vec};",
+            r#"#[cfg(feature = "alloc")]"#,
+            r"use core::str;",
+        ],
+    );
+    // Reimplements `default_read_to_end` without `BorrowedBuf` as it is unstable feature.
+    let f = BlockRegex::new(
+        f,
+        Some(r"^//.*"),
+        r"^()pub\(crate\) fn default_read_to_end<.*",
+        Some(r"^\}.*"),
+        &[
+            r"// Reads into the zero-initialized spare capacity of the vector, which grows by",
+            r"// the vector itself when it is full.",
+            r"pub(crate) fn default_read_to_end<R: Read + ?Sized>(r: &mut R, buf: &mut Vec<u8>) -> Result<usize> {",
+            r"    let start_len = buf.len();",
+            r"",
+            r"    loop {",
+            r"        if buf.len() == buf.capacity() {",
+            r"            buf.reserve(32);",
+            r"        }",
+            r"",
+            r"        let len = buf.len();",
+            r"        buf.resize(buf.capacity(), 0);",
+            r"",
+            r"        match r.read(&mut buf[len..]) {",
+            r"            Ok(0) => {",
+            r"                buf.truncate(len);",
+            r"                return Ok(len - start_len);",
+            r"            }",
+            r"            Ok(n) => buf.truncate(len + n),",
+            r"            Err(ref e) if e.kind() == ErrorKind::Interrupted => buf.truncate(len),",
+            r"            Err(e) => {",
+            r"                buf.truncate(len);",
+            r"                return Err(e);",
+            r"            }",
+            r"        }",
+            r"    }",
+            r"}",
+        ],
+    );
+
+    let f = add_cfg_attr(f, r#"feature = "alloc""#, ALLOC_ITEMS);
+
+    // Searches for the delimiter without `memchr` as it is not available.
+    let f = replace_literal(
+        f,
+        "memchr::memchr(delim, available)",
+        "available.iter().position(|b| *b == delim)",
+    );
+
+    // Removes unstable features.
+    let f = remove_fn(f, "default_read_buf").with_label("io::default_read_buf", log);
+    let f = remove_fn(f, "read_buf").with_label("io::read_buf", log);
+    let f = remove_unstable_features(f).with_label("io::unstable", log);
+
+    // Vectored I/O is kept as `IoSlice` and `IoSliceMut` are provided by the microstd `sys` module.
+    // Checking whether a reader or writer is vectored and writing all vectored buffers
+    // must have been removed above as they are unstable features.
+    let f = fail_on_line(
+        f,
+        r"^\s*fn (?:is_read_vectored|is_write_vectored|write_all_vectored)\b",
+        "Unstable vectored I/O helpers are left after the transformation.",
+    );
+
+    // Fails if most of the file has been removed by mistake.
+    let f = assert_min_lines(f, src_path, 0.25);
+
+    write_file(f, dst_path);
+}
+
+/// Imports and alters [`std::io::prelude`] module.
+fn import_prelude(src_path: &path::Path) -> impl Transformer {
+    let f = read_file(src_path);
+
+    // Removes attributes that are only allowed in internal/built-in libraries.
+    let f = remove_stable_attr(f);
+
+    return f;
+}
+
 /// Imports and alters [`std::io::error`] module.
-fn import_error(src_path: &path::Path, dst_path: &path::Path) {
+///
+/// `repr_unpacked` submodule is generated inline so that the whole module is in a single file.
+///
+/// `ErrorKind::as_str` and `impl Display for ErrorKind` are kept unchanged as the descriptions
+/// of the error kinds are static strings that don't depend on the operating system.
+/// They are also used to display OS errors.
+fn import_error(
+    src_path: &path::Path,
+    repr_src_path: &path::Path,
+    dst_path: &path::Path,
+    log: &RemovalLog,
+) {
     let f = read_file(src_path);
 
     // Removes attributes that are only allowed in internal/built-in libraries.
@@ -53,69 +248,204 @@ fn import_error(src_path: &path::Path, dst_path: &path::Path) {
     let f = remove_line(f, r##"^\s*#\[unstable\(feature = "io_error_uncategorized""##);
 
     // Removes unstable features.
-    let f = remove_unstable_features(f);
+    let f = remove_unstable_features(f).with_label("io::error::unstable", log);
 
     // Removes `repr_bitpacked` module as it uses many unstable features.
     // Always uses `repr_unpacked` instead.
     let f = remove_line(f, r".*cfg\(.*target_pointer_width.*");
     let f = remove_line(f, r"(?:mod|use) repr_bitpacked.*");
 
-    // Removes macro as it is unstable feature.
-    // It will be implemented using macro_rules!, and put to the top of the file.
-    let f = remove_block(f, &regex::escape("pub(crate) macro const_io_error("));
-
-    let f = insert_to_beginning(
+    // Converts macro to macro_rules! as declarative macros 2.0 is unstable feature.
+    let f = decl_macro_to_macro_rules(f);
+    let f = fail_on_line(
         f,
-        &[
-            r"",
-            r"/// Create and return an `io::Error` for a given `ErrorKind` and constant",
-            r"/// message. This doesn't allocate.",
-            r"macro_rules! const_io_error {",
-            r"    ($kind:expr, $message:expr $(,)?) => {",
-            r"        $crate::io::error::Error::from_static_message({",
-            r"            const MESSAGE_DATA: $crate::io::error::SimpleMessage =",
-            r"                $crate::io::error::SimpleMessage::new($kind, $message);",
-            r"            &MESSAGE_DATA",
-            r"        })",
-            r"    };",
-            r"}",
-            r"",
-        ],
+        r"^\s*(?:pub(?:\(.*\))?\s+)?macro\s+\w+",
+        "Declarative macro 2.0 is left after the conversion.",
     );
 
     // Changes custom kind to contain static string slice instead of `Box`.
-    let f = replace_text(f, &regex::escape("Box<dyn error::Error + Send + Sync>"), "&'static str");
-    let f = replace_text(
+    let f = replace_literal(f, "Box<dyn error::Error + Send + Sync>", "&'static str");
+    let f = replace_literal(f, "Box::new(Custom { kind, error })", "Custom { kind, error }");
+    let f = replace_text(f, r"\(c\) => Some\(&mut \*c.error\)", "(_) => None");
+
+    // The custom error message can be retrieved by `get_ref` as well as `into_inner`.
+    let f = replace_literal(
         f,
-        &regex::escape("Box::new(Custom { kind, error })"),
-        "Custom { kind, error }",
+        "pub fn get_ref(&self) -> Option<&(dyn error::Error + Send + Sync + 'static)>",
+        "pub fn get_ref(&self) -> Option<&'static str>",
     );
-    let f = replace_text(f, r"\(c\) => Some\(&(?:mut )?\*c.error\)", "(_) => None");
+    let f = replace_text(f, r"\(c\) => Some\(&\*c.error\)", "(c) => Some(c.error)");
     let f = replace_text(f, r"\(c\) => c\.error\.(?:cause|source)\(\)", "(_) => None");
-    let f = replace_text(f, &regex::escape("c.error.description()"), "c.error");
+    let f = replace_literal(f, "c.error.description()", "c.error");
+
+    // There is no operating system to provide the error messages.
+    // OS errors are displayed using the description of their error kind instead.
+    let f = replace_literal(
+        f,
+        "sys::os::error_string(code);",
+        "sys::decode_error_kind(code).as_str();",
+    );
+    let f = remove_line(f, &regex::escape(r#".field("message", &sys::os::error_string(code))"#));
+
+    // `Display` of the error only formats the kind and the static message with `write!`,
+    // rather than delegating to the formatting of the payload.
+    let f = within_region(f, r"^impl fmt::Display for Error \{", r"^\}", |f| {
+        let f = replace_text(f, r"=> ([\w.]+)\.fmt\(fmt\),", r#"=> write!(fmt, "{}", $1),"#);
+        // The code of an OS error isn't shown, consistently with `raw_os_error`.
+        let f = replace_literal(
+            f,
+            r#"write!(fmt, "{detail} (os error {code})")"#,
+            r#"write!(fmt, "{detail}")"#,
+        );
+        let f = fail_on_line(
+            f,
+            r"(?:\.|::)fmt\(",
+            "Display of io::Error delegates to an unknown formatting routine.",
+        );
+
+        return f;
+    });
+
+    // Errors never carry an OS error code, even the ones created from a raw code.
+    let f = replace_literal(f, "ErrorData::Os(i) => Some(i)", "ErrorData::Os(..) => None");
+    // Only the documentation of `raw_os_error` is rewritten, the other accessors share
+    // the same wording.
+    let f = within_region(
+        f,
+        r"^\s*/// Returns the OS error that this error represents",
+        r"^\s*pub fn raw_os_error\(",
+        |f| {
+            return replace_window(
+                f,
+                3,
+                r"(?s)If this \[`Error`\] was constructed via \[`last_os_error`\] or.*?it will return \[`None`\]\.",
+                "There is no operating system, hence this function always returns [`None`].",
+            );
+        },
+    );
 
     // Uses `alloc` crate.
     let f = insert_to_beginning(f, &["extern crate alloc;"]);
 
-    // Removes tests.
-    let f = remove_attr(f, r"cfg\(test\)");
+    // Removes tests. `cfg(test)` attribute is only removed if it annotates the test module.
+    let f = remove_line_before(f, r"^\s*#\[cfg\(test\)\]", r"^\s*mod tests\b", 1);
     let f = remove_line(f, "mod tests");
 
+    let f = inline_module(f, "repr_unpacked", import_error_repr_unpacked(repr_src_path));
+
+    // Fails if most of the file has been removed by mistake.
+    let f = assert_min_lines(f, src_path, 0.25);
+
     write_file(f, dst_path);
 }
 
 /// Imports and alters [`std::io`]`error/repr_unpacked` module.
-fn import_error_repr_unpacked(src_path: &path::Path, dst_path: &path::Path) {
+fn import_error_repr_unpacked(src_path: &path::Path) -> impl Transformer {
     let f = read_file(src_path);
 
     // Somehow `Repr::new` is unused.
     let f = remove_block(f, r".*fn new\(");
 
     // Custom kind is now known at compile time, hence we don't need to use `Box` anymore.
-    let f = replace_text(f, "Box<Custom>", "Custom");
+    let f = replace_literal(f, "Box<Custom>", "Custom");
 
     // Removes unused `Box`.
     let f = remove_line(f, "^use alloc::boxed::Box;");
 
-    write_file(f, dst_path);
+    return f;
+}
+
+// =================================================================================================
+// Tests
+// =================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, path, thread};
+
+    use super::*;
+
+    /// Imports the module of the standard library at `rel_path` using `import`
+    /// and returns the generated source code.
+    ///
+    /// Each test writes to its own directory as the tests run in parallel.
+    fn import_std(rel_path: &str, import: impl FnOnce(&path::Path, &path::Path)) -> String {
+        let test_name = thread::current().name().unwrap().replace("::", "_");
+
+        let rustlib_path = path::PathBuf::from(env::var("RUSTLIB_PATH").unwrap());
+        let src_path = rustlib_path.join("src/rust/library/std/src").join(rel_path);
+        let dst_path = env::temp_dir().join("eroc_microstd_builder").join(test_name).join(rel_path);
+
+        import(&src_path, &dst_path);
+
+        return fs::read_to_string(dst_path).unwrap();
+    }
+
+    #[test]
+    fn raw_os_error_docs() {
+        let text = import_std("io/error.rs", |src_path, dst_path| {
+            let repr_src_path = src_path.with_file_name("error/repr_unpacked.rs");
+            import_error(src_path, &repr_src_path, dst_path, &RemovalLog::default());
+        });
+
+        let no_os =
+            "/// There is no operating system, hence this function always returns [`None`].";
+        let from_new = "/// If this [`Error`] was constructed via [`new`] then this function will";
+
+        // Only `raw_os_error` is rewritten, `get_ref`, `get_mut` and `into_inner` are not.
+        assert_eq!(text.matches(no_os).count(), 1);
+        assert!(text.find(no_os) < text.find("pub fn raw_os_error("));
+        assert_eq!(text.matches(from_new).count(), 3);
+        assert!(!text.contains("[`from_raw_os_error`], then this function will return [`Some`]"));
+    }
+
+    #[test]
+    fn removals_are_recorded() {
+        let log = RemovalLog::default();
+        import_std("io/mod.rs", |src_path, dst_path| import_mod(src_path, dst_path, &log));
+
+        let log = log.borrow();
+        let labels = log.iter().map(|removal| removal.label.as_str()).collect::<Vec<_>>();
+
+        assert!(labels.contains(&"io::size_hint"));
+        assert!(labels.contains(&"io::read_buf"));
+        assert!(labels.contains(&"io::unstable"));
+
+        // The functions are attributed to the rules that remove them by name.
+        let is_read_buf = |removal: &&Removal| {
+            return removal.lines.iter().any(|line| line.contains("fn read_buf("));
+        };
+        assert!(log.iter().filter(is_read_buf).all(|removal| removal.label == "io::read_buf"));
+    }
+
+    #[test]
+    fn error_display_uses_write_only() {
+        let text = import_std("io/error.rs", |src_path, dst_path| {
+            let repr_src_path = src_path.with_file_name("error/repr_unpacked.rs");
+            import_error(src_path, &repr_src_path, dst_path, &RemovalLog::default());
+        });
+
+        let start = text.find("impl fmt::Display for Error {").unwrap();
+        let end = start + text[start..].find("\n}\n").unwrap();
+        let display = &text[start..end];
+
+        assert!(display.contains(r#"ErrorData::Custom(ref c) => write!(fmt, "{}", c.error),"#));
+        assert!(
+            display.contains(r#"ErrorData::SimpleMessage(msg) => write!(fmt, "{}", msg.message),"#)
+        );
+        assert!(!display.contains(".fmt(fmt)"));
+        assert!(!display.contains("os error"));
+    }
+
+    #[test]
+    fn const_io_error_is_macro_rules() {
+        let text = import_std("io/error.rs", |src_path, dst_path| {
+            let repr_src_path = src_path.with_file_name("error/repr_unpacked.rs");
+            import_error(src_path, &repr_src_path, dst_path, &RemovalLog::default());
+        });
+
+        assert!(text.contains("macro_rules! const_io_error {\n"));
+        assert!(text.contains("pub(crate) use const_io_error;\n"));
+        assert!(!text.contains("macro const_io_error("));
+    }
 }
@@ -16,4 +16,7 @@
 // limitations under the License.
 // =================================================================================================
 
+// Imported code from the standard library is not subject to lint checks.
+#![allow(clippy::all)]
+
 include!(concat!(env!("OUT_DIR"), "/rustlib/src/error.rs"));
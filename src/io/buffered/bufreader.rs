@@ -0,0 +1,227 @@
+// =================================================================================================
+// Copyright (c) 2023 Viet-Hoa Do <doviethoa@doviethoa.com>
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// =================================================================================================
+
+use core::{cmp, ptr, slice};
+
+use crate::fmt;
+use crate::io::{self, BufRead, Read};
+use crate::vec::{CommonVec, StaticVec};
+
+// =================================================================================================
+// Buffered reader
+// =================================================================================================
+
+/// Adds buffering to any reader.
+///
+/// Unlike [`std::io::BufReader`], the internal buffer is statically allocated
+/// with the capacity of `C` bytes.
+///
+/// The whole storage space of the buffer is initialized when the reader is constructed
+/// so that it can be passed to [`Read::read`] of the inner reader.
+/// Only the first `buf.len()` bytes contain valid data.
+pub struct BufReader<R, const C: usize> {
+    inner: R,
+    buf: StaticVec<u8, C>,
+    pos: usize,
+}
+
+// Constructors ------------------------------------------------------------------------------------
+
+impl<R: Read, const C: usize> BufReader<R, C> {
+    /// Creates a new `BufReader<R, C>` with the buffer capacity of `C` bytes.
+    pub fn new(inner: R) -> Self {
+        let mut buf = StaticVec::new();
+
+        // Initializes the whole storage space so that it can be passed to the inner reader.
+        unsafe {
+            ptr::write_bytes(buf.as_mut_ptr(), 0, C);
+        }
+
+        return Self { inner, buf, pos: 0 };
+    }
+
+    /// Creates a new `BufReader<R, C>` with the buffer capacity of `C` bytes.
+    ///
+    /// The capacity of the buffer is determined by the generic constant `C`
+    /// rather than the argument of this function.
+    /// This function is implemented so that it can be used as a drop-in replacement
+    /// for [`std::io::BufReader`].
+    pub fn with_capacity(_capacity: usize, inner: R) -> Self {
+        return Self::new(inner);
+    }
+}
+
+// Accessors ---------------------------------------------------------------------------------------
+
+impl<R, const C: usize> BufReader<R, C> {
+    /// Gets a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        return &self.inner;
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    ///
+    /// It is inadvisable to directly read from the underlying reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        return &mut self.inner;
+    }
+
+    /// Returns a reference to the internally buffered data.
+    ///
+    /// Unlike [`BufRead::fill_buf`], this will not attempt to fill the buffer if it is empty.
+    pub fn buffer(&self) -> &[u8] {
+        return &self.buf.as_slice()[self.pos..];
+    }
+
+    /// Returns the number of bytes the internal buffer can hold at once.
+    pub fn capacity(&self) -> usize {
+        return C;
+    }
+
+    /// Unwraps this `BufReader<R, C>`, returning the underlying reader.
+    ///
+    /// Note that any leftover data in the internal buffer is lost.
+    pub fn into_inner(self) -> R {
+        return self.inner;
+    }
+
+    /// Invalidates all data in the internal buffer.
+    fn discard_buffer(&mut self) {
+        unsafe {
+            self.buf.set_len(0);
+        }
+
+        self.pos = 0;
+    }
+}
+
+// Implement `Read` trait --------------------------------------------------------------------------
+
+impl<R: Read, const C: usize> Read for BufReader<R, C> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        // If there is no buffered data and the read is larger than the internal buffer,
+        // bypasses the internal buffer entirely.
+        if self.pos == self.buf.len() && buf.len() >= C {
+            self.discard_buffer();
+            return self.inner.read(buf);
+        }
+
+        let rem = self.fill_buf()?;
+        let nread = cmp::min(rem.len(), buf.len());
+        buf[..nread].copy_from_slice(&rem[..nread]);
+        self.consume(nread);
+
+        return Ok(nread);
+    }
+}
+
+// Implement `BufRead` trait -----------------------------------------------------------------------
+
+impl<R: Read, const C: usize> BufRead for BufReader<R, C> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.pos >= self.buf.len() {
+            self.discard_buffer();
+
+            // The whole storage space has been initialized in the constructor.
+            let storage = unsafe { slice::from_raw_parts_mut(self.buf.as_mut_ptr(), C) };
+            let nread = self.inner.read(storage)?;
+            assert!(nread <= C);
+
+            unsafe {
+                self.buf.set_len(nread);
+            }
+        }
+
+        return Ok(self.buffer());
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.pos = cmp::min(self.pos + amt, self.buf.len());
+    }
+}
+
+// Implement `Debug` trait -------------------------------------------------------------------------
+
+impl<R: fmt::Debug, const C: usize> fmt::Debug for BufReader<R, C> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return fmt
+            .debug_struct("BufReader")
+            .field("reader", &self.inner)
+            .field("buffer", &format_args!("{}/{}", self.buf.len() - self.pos, C))
+            .finish();
+    }
+}
+
+// =================================================================================================
+// Tests
+// =================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A reader that counts how many times it's read.
+    struct CountingReader<'a> {
+        data: &'a [u8],
+        reads: usize,
+    }
+
+    impl Read for CountingReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let len = buf.len().min(self.data.len());
+            buf[..len].copy_from_slice(&self.data[..len]);
+
+            self.data = &self.data[len..];
+            self.reads += 1;
+
+            return Ok(len);
+        }
+    }
+
+    #[test]
+    fn read_through_buffer() {
+        let mut reader = BufReader::<_, 4>::new(CountingReader { data: b"hello world", reads: 0 });
+        assert_eq!(reader.capacity(), 4);
+
+        assert_eq!(reader.fill_buf().unwrap(), b"hell");
+        assert_eq!(reader.get_ref().reads, 1);
+
+        let mut buf = [0u8; 2];
+        assert_eq!(reader.read(&mut buf).unwrap(), 2);
+        assert_eq!(&buf, b"he");
+        assert_eq!(reader.buffer(), b"ll");
+        assert_eq!(reader.get_ref().reads, 1);
+
+        let mut rest = [0u8; 9];
+        reader.read_exact(&mut rest).unwrap();
+        assert_eq!(&rest, b"llo world");
+        assert_eq!(reader.read(&mut buf).unwrap(), 0);
+    }
+
+    #[test]
+    fn large_read_bypasses_buffer() {
+        let mut reader = BufReader::<_, 2>::new(CountingReader { data: b"abcdef", reads: 0 });
+
+        let mut buf = [0u8; 4];
+        assert_eq!(reader.read(&mut buf).unwrap(), 4);
+        assert_eq!(&buf, b"abcd");
+        assert_eq!(reader.buffer(), b"");
+
+        assert_eq!(reader.into_inner().data, b"ef");
+    }
+}
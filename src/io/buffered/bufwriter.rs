@@ -0,0 +1,292 @@
+// =================================================================================================
+// Copyright (c) 2023 Viet-Hoa Do <doviethoa@doviethoa.com>
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// =================================================================================================
+
+use core::{mem, ptr};
+
+use super::IntoInnerError;
+use crate::fmt;
+use crate::io::{self, ErrorKind, Write};
+use crate::vec::{CommonVec, StaticVec};
+
+// =================================================================================================
+// Buffered writer
+// =================================================================================================
+
+/// Wraps a writer and buffers its output.
+///
+/// Unlike [`std::io::BufWriter`], the internal buffer is statically allocated
+/// with the capacity of `C` bytes.
+/// The buffered data is written out to the inner writer when the buffer is full,
+/// when [`Write::flush`] is called or when the writer is dropped.
+pub struct BufWriter<W: Write, const C: usize> {
+    inner: W,
+    buf: StaticVec<u8, C>,
+
+    // If the inner writer panics in a call to write, the buffered data shouldn't be written
+    // a second time in the destructor. This flag tells the destructor to skip the flush.
+    panicked: bool,
+}
+
+// Constructors and destructor ---------------------------------------------------------------------
+
+impl<W: Write, const C: usize> BufWriter<W, C> {
+    /// Creates a new `BufWriter<W, C>` with the buffer capacity of `C` bytes.
+    pub fn new(inner: W) -> Self {
+        return Self { inner, buf: StaticVec::new(), panicked: false };
+    }
+
+    /// Creates a new `BufWriter<W, C>` with the buffer capacity of `C` bytes.
+    ///
+    /// The capacity of the buffer is determined by the generic constant `C`
+    /// rather than the argument of this function.
+    /// This function is implemented so that it can be used as a drop-in replacement
+    /// for [`std::io::BufWriter`].
+    pub fn with_capacity(_capacity: usize, inner: W) -> Self {
+        return Self::new(inner);
+    }
+
+    /// Unwraps this `BufWriter<W, C>`, returning the underlying writer.
+    ///
+    /// The buffer is written out before returning the writer.
+    /// If an error occurs while writing out the buffer, returns the error
+    /// together with the buffered writer.
+    pub fn into_inner(mut self) -> Result<W, IntoInnerError<Self>> {
+        if let Err(e) = self.flush_buf() {
+            return Err(IntoInnerError::new(self, e));
+        }
+
+        let this = mem::ManuallyDrop::new(self);
+
+        // `this` is never dropped so the inner writer is only moved out once.
+        // The buffer doesn't need to be dropped as it only contains bytes.
+        return Ok(unsafe { ptr::read(&this.inner) });
+    }
+}
+
+impl<W: Write, const C: usize> Drop for BufWriter<W, C> {
+    fn drop(&mut self) {
+        if !self.panicked {
+            // Errors are ignored as there is no way to report them in the destructor.
+            let _r = self.flush_buf();
+        }
+    }
+}
+
+// Accessors ---------------------------------------------------------------------------------------
+
+impl<W: Write, const C: usize> BufWriter<W, C> {
+    /// Gets a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        return &self.inner;
+    }
+
+    /// Gets a mutable reference to the underlying writer.
+    ///
+    /// It is inadvisable to directly write to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        return &mut self.inner;
+    }
+
+    /// Returns a reference to the internally buffered data.
+    pub fn buffer(&self) -> &[u8] {
+        return self.buf.as_slice();
+    }
+
+    /// Returns the number of bytes the internal buffer can hold without flushing.
+    pub fn capacity(&self) -> usize {
+        return C;
+    }
+}
+
+// Buffer management -------------------------------------------------------------------------------
+
+impl<W: Write, const C: usize> BufWriter<W, C> {
+    /// Writes out all the buffered data to the inner writer.
+    ///
+    /// The data that has been written out is removed from the buffer even if an error occurs.
+    pub(super) fn flush_buf(&mut self) -> io::Result<()> {
+        let len = self.buf.len();
+        let mut written = 0usize;
+        let mut ret = Ok(());
+
+        while written < len {
+            self.panicked = true;
+            let r = self.inner.write(&self.buf.as_slice()[written..]);
+            self.panicked = false;
+
+            match r {
+                Ok(0) => {
+                    ret = Err(io::const_io_error!(
+                        ErrorKind::WriteZero,
+                        "failed to write the buffered data",
+                    ));
+                    break;
+                }
+                Ok(n) => written += n,
+                Err(ref e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => {
+                    ret = Err(e);
+                    break;
+                }
+            }
+        }
+
+        self.consume_buf(written);
+
+        return ret;
+    }
+
+    /// Removes the first `amt` bytes from the buffer.
+    fn consume_buf(&mut self, amt: usize) {
+        let len = self.buf.len();
+        let buf_ptr = self.buf.as_mut_ptr();
+
+        unsafe {
+            ptr::copy(buf_ptr.add(amt), buf_ptr, len - amt);
+            self.buf.set_len(len - amt);
+        }
+    }
+
+    /// Appends the data to the buffer.
+    ///
+    /// The data must fit in the spare capacity of the buffer.
+    pub(super) fn write_to_buf(&mut self, data: &[u8]) {
+        let len = self.buf.len();
+        assert!(data.len() <= C - len);
+
+        unsafe {
+            ptr::copy_nonoverlapping(data.as_ptr(), self.buf.as_mut_ptr().add(len), data.len());
+            self.buf.set_len(len + data.len());
+        }
+    }
+}
+
+// Implement `Write` trait -------------------------------------------------------------------------
+
+impl<W: Write, const C: usize> Write for BufWriter<W, C> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.buf.len() + buf.len() > C {
+            self.flush_buf()?;
+        }
+
+        // If the data is at least as large as the buffer, bypasses the buffer entirely.
+        if buf.len() >= C {
+            self.panicked = true;
+            let r = self.inner.write(buf);
+            self.panicked = false;
+
+            return r;
+        } else {
+            self.write_to_buf(buf);
+            return Ok(buf.len());
+        }
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        if self.buf.len() + buf.len() > C {
+            self.flush_buf()?;
+        }
+
+        // If the data is at least as large as the buffer, bypasses the buffer entirely.
+        if buf.len() >= C {
+            self.panicked = true;
+            let r = self.inner.write_all(buf);
+            self.panicked = false;
+
+            return r;
+        } else {
+            self.write_to_buf(buf);
+            return Ok(());
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.flush_buf()?;
+        return self.inner.flush();
+    }
+}
+
+// Implement `Debug` trait -------------------------------------------------------------------------
+
+impl<W: Write + fmt::Debug, const C: usize> fmt::Debug for BufWriter<W, C> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return fmt
+            .debug_struct("BufWriter")
+            .field("writer", &self.inner)
+            .field("buffer", &format_args!("{}/{}", self.buf.len(), C))
+            .finish();
+    }
+}
+
+// =================================================================================================
+// Tests
+// =================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::buffered::testing::RecordingWriter;
+
+    #[test]
+    fn write_through_buffer() {
+        let mut writer = BufWriter::<_, 4>::new(RecordingWriter::default());
+        assert_eq!(writer.capacity(), 4);
+
+        writer.write_all(b"ab").unwrap();
+        writer.write_all(b"cd").unwrap();
+        assert!(writer.get_ref().writes.is_empty());
+
+        // The buffer is flushed when it's full.
+        writer.write_all(b"e").unwrap();
+        assert_eq!(writer.get_ref().writes.as_slice(), [4]);
+        assert_eq!(writer.buffer(), b"e");
+
+        // Large writes bypass the buffer after flushing it.
+        writer.write_all(b"0123456").unwrap();
+        assert_eq!(writer.get_ref().writes.as_slice(), [4, 1, 7]);
+
+        write!(writer, "{}", 42).unwrap();
+
+        let inner = writer.into_inner().unwrap();
+        assert_eq!(inner.data.as_slice(), b"abcde012345642");
+    }
+
+    #[test]
+    fn drop_flushes_buffer() {
+        /// A writer that appends to a vector it borrows.
+        struct BorrowingWriter<'a>(&'a mut StaticVec<u8, 8>);
+
+        impl Write for BorrowingWriter<'_> {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                return self.0.write(buf);
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                return Ok(());
+            }
+        }
+
+        let mut data = StaticVec::new();
+
+        let mut writer = BufWriter::<_, 8>::new(BorrowingWriter(&mut data));
+        writer.write_all(b"xy").unwrap();
+        drop(writer);
+
+        assert_eq!(data.as_slice(), b"xy");
+    }
+}
@@ -0,0 +1,200 @@
+// =================================================================================================
+// Copyright (c) 2023 Viet-Hoa Do <doviethoa@doviethoa.com>
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// =================================================================================================
+
+use super::{BufWriter, IntoInnerError};
+use crate::fmt;
+use crate::io::{self, Write};
+
+// =================================================================================================
+// Line writer
+// =================================================================================================
+
+/// Wraps a writer and buffers its output, flushing it whenever a newline is written.
+///
+/// Unlike [`std::io::LineWriter`], the internal buffer is statically allocated
+/// with the capacity of `C` bytes.
+/// The buffered data is also written out to the inner writer when the buffer is full,
+/// when [`Write::flush`] is called or when the writer is dropped.
+pub struct LineWriter<W: Write, const C: usize> {
+    inner: BufWriter<W, C>,
+}
+
+// Constructors ------------------------------------------------------------------------------------
+
+impl<W: Write, const C: usize> LineWriter<W, C> {
+    /// Creates a new `LineWriter<W, C>` with the buffer capacity of `C` bytes.
+    pub fn new(inner: W) -> Self {
+        return Self { inner: BufWriter::new(inner) };
+    }
+
+    /// Creates a new `LineWriter<W, C>` with the buffer capacity of `C` bytes.
+    ///
+    /// The capacity of the buffer is determined by the generic constant `C`
+    /// rather than the argument of this function.
+    /// This function is implemented so that it can be used as a drop-in replacement
+    /// for [`std::io::LineWriter`].
+    pub fn with_capacity(_capacity: usize, inner: W) -> Self {
+        return Self::new(inner);
+    }
+
+    /// Unwraps this `LineWriter<W, C>`, returning the underlying writer.
+    ///
+    /// The buffer is written out before returning the writer.
+    /// If an error occurs while writing out the buffer, returns the error
+    /// together with the line writer.
+    pub fn into_inner(self) -> Result<W, IntoInnerError<Self>> {
+        return self.inner.into_inner().map_err(|IntoInnerError(inner, e)| {
+            return IntoInnerError::new(Self { inner }, e);
+        });
+    }
+}
+
+// Accessors ---------------------------------------------------------------------------------------
+
+impl<W: Write, const C: usize> LineWriter<W, C> {
+    /// Gets a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        return self.inner.get_ref();
+    }
+
+    /// Gets a mutable reference to the underlying writer.
+    ///
+    /// It is inadvisable to directly write to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        return self.inner.get_mut();
+    }
+}
+
+// Implement `Write` trait -------------------------------------------------------------------------
+
+impl<W: Write, const C: usize> Write for LineWriter<W, C> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let newline_idx = match buf.iter().rposition(|b| *b == b'\n') {
+            Some(idx) => idx,
+            None => return self.inner.write(buf),
+        };
+
+        // The complete lines are written out together with the previously buffered data.
+        // The buffer is empty after that, so the incomplete line is buffered as much as it fits.
+        self.inner.flush_buf()?;
+
+        let lines = &buf[..=newline_idx];
+        let written = self.inner.get_mut().write(lines)?;
+
+        if written < lines.len() {
+            return Ok(written);
+        }
+
+        let tail = &buf[written..];
+        let buffered = tail.len().min(C);
+        self.inner.write_to_buf(&tail[..buffered]);
+
+        return Ok(written + buffered);
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        return self.inner.flush();
+    }
+}
+
+// Implement `Debug` trait -------------------------------------------------------------------------
+
+impl<W: Write + fmt::Debug, const C: usize> fmt::Debug for LineWriter<W, C> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return fmt
+            .debug_struct("LineWriter")
+            .field("writer", self.get_ref())
+            .field("buffer", &format_args!("{}/{}", self.inner.buffer().len(), C))
+            .finish();
+    }
+}
+
+// =================================================================================================
+// Tests
+// =================================================================================================
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use crate::io::buffered::testing::RecordingWriter;
+    use crate::vec::CommonVec;
+
+    #[test]
+    fn write_flushes_complete_lines() {
+        let mut writer = LineWriter::<_, 8>::new(RecordingWriter::default());
+
+        writer.write_all(b"ab").unwrap();
+        assert!(writer.get_ref().writes.is_empty());
+
+        // The buffered data is written out first, then the complete lines.
+        writer.write_all(b"c\nd\nef").unwrap();
+        assert_eq!(writer.get_ref().writes.as_slice(), [2, 4]);
+        assert_eq!(writer.get_ref().data.as_slice(), b"abc\nd\n");
+
+        writer.flush().unwrap();
+        assert_eq!(writer.get_ref().writes.as_slice(), [2, 4, 2]);
+
+        writer.write_all(b"gh").unwrap();
+
+        let inner = writer.into_inner().unwrap();
+        assert_eq!(inner.data.as_slice(), b"abc\nd\nefgh");
+    }
+
+    #[test]
+    fn write_buffers_tail_as_much_as_fits() {
+        let mut writer = LineWriter::<_, 2>::new(RecordingWriter::default());
+
+        assert_eq!(writer.write(b"a\nbcd").unwrap(), 4);
+        assert_eq!(writer.get_ref().data.as_slice(), b"a\n");
+
+        writer.write_all(b"d").unwrap();
+
+        let inner = writer.into_inner().unwrap();
+        assert_eq!(inner.data.as_slice(), b"a\nbcd");
+    }
+
+    #[test]
+    fn write_flushes_full_buffer_without_newline() {
+        let mut writer = LineWriter::<_, 4>::new(RecordingWriter::default());
+
+        writer.write_all(b"abc").unwrap();
+        assert!(writer.get_ref().writes.is_empty());
+
+        // The buffered data is written out when the new data doesn't fit.
+        writer.write_all(b"de").unwrap();
+        assert_eq!(writer.get_ref().writes.as_slice(), [3]);
+        assert_eq!(writer.get_ref().data.as_slice(), b"abc");
+
+        // Data larger than the buffer bypasses it.
+        writer.write_all(b"fghij").unwrap();
+        assert_eq!(writer.get_ref().writes.as_slice(), [3, 2, 5]);
+        assert_eq!(writer.get_ref().data.as_slice(), b"abcdefghij");
+    }
+
+    #[test]
+    fn debug() {
+        let mut writer = LineWriter::<_, 8>::new(RecordingWriter::default());
+        writer.write_all(b"abc").unwrap();
+
+        let text = std::format!("{:?}", writer);
+        assert!(text.starts_with("LineWriter { writer: RecordingWriter"));
+        assert!(text.ends_with("buffer: 3/8 }"));
+    }
+}
@@ -0,0 +1,86 @@
+// =================================================================================================
+// Copyright (c) 2023 Viet-Hoa Do <doviethoa@doviethoa.com>
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// =================================================================================================
+
+//! Buffering wrappers for I/O traits using statically allocated buffers.
+
+mod bufreader;
+mod bufwriter;
+mod linewriter;
+#[cfg(test)]
+mod testing;
+
+use crate::error;
+use crate::fmt;
+use crate::io::Error;
+
+pub use bufreader::BufReader;
+pub use bufwriter::BufWriter;
+pub use linewriter::LineWriter;
+
+// =================================================================================================
+// IntoInnerError
+// =================================================================================================
+
+/// An error returned by [`BufWriter::into_inner`] which combines an error that
+/// happened while writing out the buffer, and the buffered writer object
+/// which may be used to recover from the condition.
+#[derive(Debug)]
+pub struct IntoInnerError<W>(W, Error);
+
+impl<W> IntoInnerError<W> {
+    /// Constructs a new `IntoInnerError`.
+    fn new(writer: W, error: Error) -> Self {
+        return Self(writer, error);
+    }
+
+    /// Returns the error which caused the call to [`BufWriter::into_inner`] to fail.
+    pub fn error(&self) -> &Error {
+        return &self.1;
+    }
+
+    /// Returns the buffered writer instance which generated the error.
+    pub fn into_inner(self) -> W {
+        return self.0;
+    }
+
+    /// Consumes the `IntoInnerError` and returns the error which caused
+    /// the call to [`BufWriter::into_inner`] to fail.
+    pub fn into_error(self) -> Error {
+        return self.1;
+    }
+
+    /// Consumes the `IntoInnerError` and returns the error which caused
+    /// the call to [`BufWriter::into_inner`] to fail, and the underlying writer.
+    pub fn into_parts(self) -> (Error, W) {
+        return (self.1, self.0);
+    }
+}
+
+impl<W> From<IntoInnerError<W>> for Error {
+    fn from(iie: IntoInnerError<W>) -> Error {
+        return iie.1;
+    }
+}
+
+impl<W: Send + fmt::Debug> error::Error for IntoInnerError<W> {}
+
+impl<W> fmt::Display for IntoInnerError<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return self.error().fmt(f);
+    }
+}
@@ -0,0 +1,42 @@
+// =================================================================================================
+// Copyright (c) 2023 Viet-Hoa Do <doviethoa@doviethoa.com>
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// =================================================================================================
+
+//! Helpers shared by the tests of the buffered wrappers.
+
+use crate::io::{self, Write};
+use crate::vec::{CommonVec, StaticVec};
+
+/// A writer that records the size of each write.
+#[derive(Debug, Default)]
+pub struct RecordingWriter {
+    pub data: StaticVec<u8, 32>,
+    pub writes: StaticVec<usize, 8>,
+}
+
+impl Write for RecordingWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.data.extend(buf.iter().copied());
+        self.writes.push(buf.len());
+
+        return Ok(buf.len());
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        return Ok(());
+    }
+}
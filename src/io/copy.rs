@@ -0,0 +1,158 @@
+// =================================================================================================
+// Copyright (c) 2023 Viet-Hoa Do <doviethoa@doviethoa.com>
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// =================================================================================================
+
+use crate::io::{ErrorKind, Read, Result, Write};
+use crate::vec::{CommonVec, StaticVec};
+
+/// The size of the buffer on the stack used by [`copy`].
+///
+/// It is much smaller than the buffer of [`std::io::copy`] as the stack size
+/// is usually limited in `no_std` environment.
+const DEFAULT_BUF_SIZE: usize = 512;
+
+/// Copies the entire contents of a reader into a writer.
+///
+/// This function will continuously read data from `reader` and then write it into `writer`
+/// until `reader` returns EOF. On success, the total number of bytes that were copied
+/// from `reader` to `writer` is returned.
+///
+/// Unlike [`std::io::copy`], the data is always copied through a buffer of 512 bytes
+/// on the stack so that no dynamic memory allocation is needed.
+/// The function keeps the signature of [`std::io::copy`] so that it can be used
+/// as a drop-in replacement. Use [`copy_with_buffer`] to choose the size of the buffer.
+///
+/// Errors of kind [`ErrorKind::Interrupted`] returned by `reader` are ignored
+/// and the read operation is retried.
+pub fn copy<R: ?Sized, W: ?Sized>(reader: &mut R, writer: &mut W) -> Result<u64>
+where
+    R: Read,
+    W: Write,
+{
+    return copy_with_buffer::<DEFAULT_BUF_SIZE, R, W>(reader, writer);
+}
+
+/// Copies the entire contents of a reader into a writer through a buffer of `N` bytes.
+///
+/// The buffer is a [`StaticVec<u8, N>`] on the stack. Otherwise this function is the same
+/// as [`copy`]. Panics if `N` is 0 as nothing could ever be read into the buffer.
+pub fn copy_with_buffer<const N: usize, R: ?Sized, W: ?Sized>(
+    reader: &mut R,
+    writer: &mut W,
+) -> Result<u64>
+where
+    R: Read,
+    W: Write,
+{
+    assert!(N != 0, "Buffer size is zero.");
+
+    let mut buf = StaticVec::<u8, N>::new();
+    buf.resize_with(N, || 0);
+
+    let mut written = 0u64;
+
+    loop {
+        let len = match reader.read(buf.as_mut_slice()) {
+            Ok(0) => return Ok(written),
+            Ok(len) => len,
+            Err(ref e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+
+        writer.write_all(&buf.as_slice()[..len])?;
+        written += len as u64;
+    }
+}
+
+// =================================================================================================
+// Tests
+// =================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::const_io_error;
+
+    /// A reader that returns the data in chunks of at most 2 bytes,
+    /// and is interrupted before each chunk.
+    struct ChunkReader<'a> {
+        data: &'a [u8],
+        interrupted: bool,
+    }
+
+    impl Read for ChunkReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+            self.interrupted = !self.interrupted;
+
+            if self.interrupted {
+                return Err(const_io_error!(ErrorKind::Interrupted, "interrupted"));
+            }
+
+            let len = buf.len().min(self.data.len()).min(2);
+            buf[..len].copy_from_slice(&self.data[..len]);
+            self.data = &self.data[len..];
+
+            return Ok(len);
+        }
+    }
+
+    #[test]
+    fn copy_all() {
+        let src = StaticVec::<u8, 16>::from_array(*b"hello, world");
+        let mut dst = StaticVec::<u8, 16>::new();
+
+        assert_eq!(copy(&mut src.cursor(), &mut dst).unwrap(), 12);
+        assert_eq!(dst.as_slice(), b"hello, world");
+    }
+
+    #[test]
+    fn copy_through_small_buffer() {
+        let src = StaticVec::<u8, 16>::from_array(*b"hello, world");
+        let mut dst = StaticVec::<u8, 16>::new();
+
+        assert_eq!(copy_with_buffer::<5, _, _>(&mut src.cursor(), &mut dst).unwrap(), 12);
+        assert_eq!(dst.as_slice(), b"hello, world");
+    }
+
+    #[test]
+    #[should_panic(expected = "Buffer size is zero.")]
+    fn copy_through_zero_size_buffer() {
+        let src = StaticVec::<u8, 16>::from_array(*b"hello, world");
+        let mut dst = StaticVec::<u8, 16>::new();
+
+        let _ = copy_with_buffer::<0, _, _>(&mut src.cursor(), &mut dst);
+    }
+
+    #[test]
+    fn copy_retries_interrupted_reads() {
+        let mut reader = ChunkReader { data: b"abcde", interrupted: false };
+        let mut dst = StaticVec::<u8, 8>::new();
+
+        assert_eq!(copy(&mut reader, &mut dst).unwrap(), 5);
+        assert_eq!(dst.as_slice(), b"abcde");
+    }
+
+    #[test]
+    fn copy_into_full_writer() {
+        let src = StaticVec::<u8, 16>::from_array(*b"hello, world");
+        let mut dst = StaticVec::<u8, 4>::new();
+
+        let error = copy(&mut src.cursor(), &mut dst).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::WriteZero);
+        assert_eq!(dst.as_slice(), b"hell");
+    }
+}
@@ -16,4 +16,87 @@
 // limitations under the License.
 // =================================================================================================
 
-include!(concat!(env!("OUT_DIR"), "/rustlib/src/io/error/mod.rs"));
+// Imported code from the standard library is not subject to lint checks.
+#![allow(clippy::all)]
+
+include!(concat!(env!("OUT_DIR"), "/rustlib/src/io/error.rs"));
+
+// =================================================================================================
+// Tests
+// =================================================================================================
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(Error::new(ErrorKind::Other, "custom message").to_string(), "custom message");
+        assert_eq!(Error::from(ErrorKind::NotFound).to_string(), "entity not found");
+        assert_eq!(const_io_error!(ErrorKind::InvalidData, "simple").to_string(), "simple");
+    }
+
+    #[test]
+    fn display_os_error_without_code() {
+        let error = Error::from_raw_os_error(2);
+
+        assert_eq!(error.kind(), ErrorKind::Other);
+        assert_eq!(error.to_string(), "other error");
+    }
+
+    #[test]
+    fn raw_os_error_is_none() {
+        assert_eq!(Error::from(ErrorKind::NotFound).raw_os_error(), None);
+        assert_eq!(Error::new(ErrorKind::Other, "custom").raw_os_error(), None);
+        assert_eq!(const_io_error!(ErrorKind::InvalidData, "simple").raw_os_error(), None);
+        assert_eq!(Error::from_raw_os_error(2).raw_os_error(), None);
+    }
+
+    #[test]
+    fn const_io_error_static_message() {
+        let error = const_io_error!(ErrorKind::NotFound, "missing",);
+
+        assert_eq!(error.kind(), ErrorKind::NotFound);
+        assert_eq!(error.to_string(), "missing");
+        assert!(std::format!("{:?}", error).contains("\"missing\""));
+    }
+
+    #[test]
+    fn error_kind_display_matches_std() {
+        let kinds = [
+            (ErrorKind::NotFound, std::io::ErrorKind::NotFound),
+            (ErrorKind::PermissionDenied, std::io::ErrorKind::PermissionDenied),
+            (ErrorKind::InvalidInput, std::io::ErrorKind::InvalidInput),
+            (ErrorKind::InvalidData, std::io::ErrorKind::InvalidData),
+            (ErrorKind::UnexpectedEof, std::io::ErrorKind::UnexpectedEof),
+            (ErrorKind::WriteZero, std::io::ErrorKind::WriteZero),
+            (ErrorKind::Interrupted, std::io::ErrorKind::Interrupted),
+            (ErrorKind::OutOfMemory, std::io::ErrorKind::OutOfMemory),
+            (ErrorKind::Other, std::io::ErrorKind::Other),
+        ];
+
+        for (kind, std_kind) in kinds {
+            assert_eq!(kind.to_string(), std_kind.to_string());
+        }
+    }
+
+    #[test]
+    fn custom_message_round_trip() {
+        let mut error = Error::new(ErrorKind::InvalidInput, "bad input");
+
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+        assert_eq!(error.get_ref(), Some("bad input"));
+        assert!(error.get_mut().is_none());
+        assert_eq!(error.into_inner(), Some("bad input"));
+
+        let error = Error::from(ErrorKind::NotFound);
+        assert_eq!(error.get_ref(), None);
+        assert_eq!(error.into_inner(), None);
+
+        assert_eq!(const_io_error!(ErrorKind::Other, "simple").get_ref(), None);
+    }
+}
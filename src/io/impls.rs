@@ -0,0 +1,158 @@
+// =================================================================================================
+// Copyright (c) 2023 Viet-Hoa Do <doviethoa@doviethoa.com>
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// =================================================================================================
+
+use crate::io::{self, ErrorKind, Read, Result, Seek, SeekFrom, Write};
+use crate::vec::{CommonVec, StaticVec, StaticVecCursor};
+
+// =================================================================================================
+// In-memory readers
+// =================================================================================================
+
+/// Copies the bytes from the current position of the cursor and advances the cursor past them.
+impl Read for StaticVecCursor<'_, u8> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let src = self.take(buf.len());
+        buf[..src.len()].copy_from_slice(src);
+
+        return Ok(src.len());
+    }
+}
+
+/// Moves the cursor within the elements of the vector.
+///
+/// Unlike [`std::io::Cursor`], the cursor cannot be moved past the end of the vector,
+/// hence seeking there fails with [`ErrorKind::InvalidInput`] and leaves the cursor unchanged.
+impl<T> Seek for StaticVecCursor<'_, T> {
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        let len = self.position() + self.remaining().len();
+
+        let new_pos = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => (len as u64).checked_add_signed(offset),
+            SeekFrom::Current(offset) => (self.position() as u64).checked_add_signed(offset),
+        };
+
+        match new_pos.filter(|new_pos| *new_pos <= len as u64) {
+            Some(new_pos) => {
+                self.set_position(new_pos as usize);
+                return Ok(new_pos);
+            }
+            None => {
+                return Err(io::const_io_error!(
+                    ErrorKind::InvalidInput,
+                    "invalid seek to a negative position or past the end",
+                ));
+            }
+        }
+    }
+}
+
+// =================================================================================================
+// In-memory writers
+// =================================================================================================
+
+/// Appends the written bytes to the vector.
+///
+/// As the capacity is fixed, [`Write::write`] only copies as many bytes as fit
+/// and returns `Ok(0)` once the vector is full, hence [`Write::write_all`] fails
+/// with [`ErrorKind::WriteZero`](crate::io::ErrorKind::WriteZero).
+impl<const C: usize> Write for StaticVec<u8, C> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        let len = buf.len().min(C - self.len());
+        self.extend(buf[..len].iter().copied());
+
+        return Ok(len);
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        return Ok(());
+    }
+}
+
+// =================================================================================================
+// Tests
+// =================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_static_vec() {
+        let mut vec = StaticVec::<u8, 8>::new();
+
+        assert_eq!(vec.write(b"hello").unwrap(), 5);
+        assert_eq!(vec.write(b" world").unwrap(), 3);
+        assert_eq!(vec.write(b"!").unwrap(), 0);
+        assert_eq!(vec.as_slice(), b"hello wo");
+
+        let error = vec.write_all(b"!").unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::WriteZero);
+    }
+
+    #[test]
+    fn read_cursor() {
+        let vec = StaticVec::<u8, 8>::from_array(*b"abcdef");
+        let mut cursor = vec.cursor();
+        let mut buf = [0u8; 4];
+
+        assert_eq!(cursor.read(&mut buf).unwrap(), 4);
+        assert_eq!(&buf, b"abcd");
+        assert_eq!(cursor.read(&mut buf).unwrap(), 2);
+        assert_eq!(&buf[..2], b"ef");
+        assert_eq!(cursor.read(&mut buf).unwrap(), 0);
+    }
+
+    #[test]
+    fn seek_cursor() {
+        let vec = StaticVec::<u8, 8>::from_array(*b"abcdef");
+        let mut cursor = vec.cursor();
+
+        assert_eq!(cursor.seek(SeekFrom::Start(2)).unwrap(), 2);
+        assert_eq!(cursor.peek(), Some(&b'c'));
+
+        assert_eq!(cursor.seek(SeekFrom::Current(3)).unwrap(), 5);
+        assert_eq!(cursor.seek(SeekFrom::Current(-4)).unwrap(), 1);
+        assert_eq!(cursor.seek(SeekFrom::End(-1)).unwrap(), 5);
+        assert_eq!(cursor.peek(), Some(&b'f'));
+
+        assert_eq!(cursor.seek(SeekFrom::End(0)).unwrap(), 6);
+        assert_eq!(cursor.peek(), None);
+
+        cursor.rewind().unwrap();
+        assert_eq!(cursor.stream_position().unwrap(), 0);
+
+        let mut buf = [0u8; 3];
+        cursor.seek(SeekFrom::Start(3)).unwrap();
+        cursor.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"def");
+    }
+
+    #[test]
+    fn seek_cursor_out_of_range() {
+        let vec = StaticVec::<u8, 8>::from_array(*b"abcdef");
+        let mut cursor = vec.cursor();
+        cursor.seek(SeekFrom::Start(4)).unwrap();
+
+        for pos in [SeekFrom::Start(7), SeekFrom::End(1), SeekFrom::Current(-5)] {
+            let error = cursor.seek(pos).unwrap_err();
+            assert_eq!(error.kind(), ErrorKind::InvalidInput);
+            assert_eq!(cursor.position(), 4);
+        }
+    }
+}
@@ -16,6 +16,187 @@
 // limitations under the License.
 // =================================================================================================
 
+mod buffered;
+mod copy;
 mod error;
+mod impls;
 
-pub use error::*;
+include!(concat!(env!("OUT_DIR"), "/rustlib/src/io/mod.rs"));
+
+// =================================================================================================
+// Tests
+// =================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A reader that returns the data it borrows.
+    struct SliceReader<'a>(&'a [u8]);
+
+    impl Read for SliceReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+            let len = buf.len().min(self.0.len());
+            buf[..len].copy_from_slice(&self.0[..len]);
+            self.0 = &self.0[len..];
+
+            return Ok(len);
+        }
+    }
+
+    // Prelude -------------------------------------------------------------------------------------
+
+    #[test]
+    fn prelude() {
+        use crate::io::prelude::*;
+
+        fn is_buf_read<T: BufRead>(_: &T) {}
+        fn is_write<T: Write>(_: &T) {}
+        fn is_seek<T: Seek>(_: &T) {}
+
+        let mut reader = BufReader::<_, 4>::new(SliceReader(b"abc"));
+        is_buf_read(&reader);
+
+        let mut buf = [0u8; 3];
+        reader.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"abc");
+
+        is_write(&crate::vec::StaticVec::<u8, 4>::new());
+        is_seek(&crate::vec::StaticVec::<u8, 4>::new().cursor());
+    }
+
+    // Alloc ---------------------------------------------------------------------------------------
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn read_to_end_and_string() {
+        let mut buf = Vec::from(*b"> ");
+        assert_eq!(SliceReader(b"hello").read_to_end(&mut buf).unwrap(), 5);
+        assert_eq!(buf, b"> hello");
+
+        let mut text = String::new();
+        assert_eq!(SliceReader("héllo".as_bytes()).read_to_string(&mut text).unwrap(), 6);
+        assert_eq!(text, "héllo");
+
+        let error = SliceReader(b"\xff").read_to_string(&mut text).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+        assert_eq!(text, "héllo");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn read_lines() {
+        let mut reader = BufReader::<_, 4>::new(SliceReader(b"first\nsecond\nlast"));
+
+        let mut line = String::new();
+        assert_eq!(reader.read_line(&mut line).unwrap(), 6);
+        assert_eq!(line, "first\n");
+
+        let mut until = Vec::new();
+        assert_eq!(reader.read_until(b'o', &mut until).unwrap(), 4);
+        assert_eq!(until, b"seco");
+
+        let lines = reader.lines().collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(lines, ["nd", "last"]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn split() {
+        let reader = BufReader::<_, 2>::new(SliceReader(b"a,bc,,d"));
+
+        let parts = reader.split(b',').collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(parts, [&b"a"[..], b"bc", b"", b"d"]);
+    }
+
+    // Adapters ------------------------------------------------------------------------------------
+
+    #[test]
+    fn take() {
+        let mut take: Take<_> = SliceReader(b"hello world").take(5);
+        let mut buf = [0u8; 16];
+
+        assert_eq!(take.read(&mut buf).unwrap(), 5);
+        assert_eq!(&buf[..5], b"hello");
+        assert_eq!(take.read(&mut buf).unwrap(), 0);
+        assert_eq!(take.limit(), 0);
+
+        take.set_limit(3);
+        assert_eq!(take.read(&mut buf).unwrap(), 3);
+        assert_eq!(&buf[..3], b" wo");
+        assert_eq!(take.into_inner().0, b"rld");
+    }
+
+    #[test]
+    fn chain() {
+        let mut chain: Chain<_, _> = SliceReader(b"ab").chain(SliceReader(b"cd"));
+
+        let mut buf = [0u8; 4];
+        chain.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"abcd");
+        assert_eq!(chain.read(&mut buf).unwrap(), 0);
+
+        let (first, second) = chain.into_inner();
+        assert!(first.0.is_empty() && second.0.is_empty());
+    }
+
+    #[test]
+    fn bytes() {
+        let mut bytes = SliceReader(b"abc").bytes();
+
+        assert_eq!(bytes.next().unwrap().unwrap(), b'a');
+        assert_eq!(bytes.next().unwrap().unwrap(), b'b');
+        assert_eq!(bytes.next().unwrap().unwrap(), b'c');
+        assert!(bytes.next().is_none());
+
+        /// A reader that fails once, then reaches the end of file.
+        struct FailingReader(bool);
+
+        impl Read for FailingReader {
+            fn read(&mut self, _buf: &mut [u8]) -> Result<usize> {
+                if self.0 {
+                    return Ok(0);
+                }
+
+                self.0 = true;
+                return Err(Error::from(ErrorKind::InvalidData));
+            }
+        }
+
+        let mut bytes = FailingReader(false).bytes();
+        assert_eq!(bytes.next().unwrap().unwrap_err().kind(), ErrorKind::InvalidData);
+        assert!(bytes.next().is_none());
+    }
+
+    // Vectored I/O --------------------------------------------------------------------------------
+
+    #[test]
+    fn read_vectored() {
+        let mut reader = SliceReader(b"abcdef");
+        let mut first = [0u8; 0];
+        let mut second = [0u8; 4];
+        let mut third = [0u8; 4];
+
+        let mut bufs = [
+            IoSliceMut::new(&mut first),
+            IoSliceMut::new(&mut second),
+            IoSliceMut::new(&mut third),
+        ];
+
+        // The default implementation reads into the first non-empty buffer only.
+        assert_eq!(reader.read_vectored(&mut bufs).unwrap(), 4);
+        assert_eq!(&*bufs[1], b"abcd");
+        assert_eq!(reader.read_vectored(&mut bufs).unwrap(), 2);
+        assert_eq!(&bufs[1][..2], b"ef");
+    }
+
+    #[test]
+    fn write_vectored() {
+        let mut writer = crate::vec::StaticVec::<u8, 8>::new();
+        let bufs = [IoSlice::new(b""), IoSlice::new(b"ab"), IoSlice::new(b"cd")];
+
+        assert_eq!(bufs[1].len(), 2);
+        assert_eq!(writer.write_vectored(&bufs).unwrap(), 2);
+        assert_eq!(&*writer, b"ab");
+    }
+}
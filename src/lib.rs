@@ -19,6 +19,7 @@
 //! An alternative implemenation of the Rust standard library for `no_std` environment.
 
 #![no_std]
+#![allow(clippy::needless_return)]
 
 mod builtin;
 pub use builtin::*;
@@ -28,4 +29,5 @@ pub mod io;
 
 mod sys;
 
+pub mod string;
 pub mod vec;
@@ -0,0 +1,277 @@
+// =================================================================================================
+// Copyright (c) 2023 Viet-Hoa Do <doviethoa@doviethoa.com>
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// =================================================================================================
+
+use core::{fmt, ops, str};
+
+use crate::vec::{CommonVec, StaticVec, TryReserveError};
+
+// =================================================================================================
+// Static string
+// =================================================================================================
+
+/// A UTF-8 encoded string statically allocated with the capacity of `C` bytes.
+///
+/// The bytes are stored in a [`StaticVec<u8, C>`] which always contains valid UTF-8.
+pub struct StaticString<const C: usize> {
+    vec: StaticVec<u8, C>,
+}
+
+// Constructors ------------------------------------------------------------------------------------
+
+impl<const C: usize> StaticString<C> {
+    /// Constructs a new, empty `StaticString<C>`.
+    pub const fn new() -> Self {
+        return Self { vec: StaticVec::new() };
+    }
+}
+
+impl<const C: usize> Default for StaticString<C> {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+// Static string methods ---------------------------------------------------------------------------
+
+impl<const C: usize> StaticString<C> {
+    /// Returns the number of bytes that the string can hold.
+    pub fn capacity(&self) -> usize {
+        return C;
+    }
+
+    /// Returns a string slice that contains the entire string.
+    pub fn as_str(&self) -> &str {
+        // The vector only ever contains complete UTF-8 encoded strings.
+        return unsafe { str::from_utf8_unchecked(self.vec.as_slice()) };
+    }
+
+    /// Returns a mutable string slice that contains the entire string.
+    pub fn as_mut_str(&mut self) -> &mut str {
+        // The vector only ever contains complete UTF-8 encoded strings.
+        return unsafe { str::from_utf8_unchecked_mut(self.vec.as_mut_slice()) };
+    }
+
+    /// Returns a byte slice that contains the UTF-8 encoded string.
+    pub fn as_bytes(&self) -> &[u8] {
+        return self.vec.as_slice();
+    }
+
+    /// Appends a character to the end of the string.
+    ///
+    /// Panics if the string doesn't have enough capacity for the encoded character.
+    pub fn push(&mut self, ch: char) {
+        self.try_push(ch).unwrap();
+    }
+
+    /// Tries to append a character to the end of the string.
+    ///
+    /// If the string doesn't have enough capacity for the encoded character,
+    /// returns an error and the string is left unchanged.
+    pub fn try_push(&mut self, ch: char) -> Result<(), TryReserveError> {
+        return self.try_push_str(ch.encode_utf8(&mut [0; 4]));
+    }
+
+    /// Appends a string slice to the end of the string.
+    ///
+    /// Panics if the string doesn't have enough capacity for `s`.
+    pub fn push_str(&mut self, s: &str) {
+        self.try_push_str(s).unwrap();
+    }
+
+    /// Tries to append a string slice to the end of the string.
+    ///
+    /// If the string doesn't have enough capacity for `s`,
+    /// returns an error and the string is left unchanged.
+    pub fn try_push_str(&mut self, s: &str) -> Result<(), TryReserveError> {
+        self.vec.try_reserve(s.len())?;
+        self.vec.extend(s.bytes());
+
+        return Ok(());
+    }
+
+    /// Removes the last character from the string and returns it,
+    /// or `None` if the string is empty.
+    pub fn pop(&mut self) -> Option<char> {
+        let ch = self.as_str().chars().next_back()?;
+        self.vec.truncate(self.vec.len() - ch.len_utf8());
+
+        return Some(ch);
+    }
+
+    /// Shortens the string to the first `len` bytes.
+    ///
+    /// If the current length is less than `len`, does nothing.
+    /// Panics if `len` doesn't lie on a character boundary.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.vec.len() {
+            assert!(self.as_str().is_char_boundary(len), "Length is not on a char boundary.");
+            self.vec.truncate(len);
+        }
+    }
+
+    /// Removes all the characters from the string.
+    pub fn clear(&mut self) {
+        self.vec.clear();
+    }
+}
+
+// Standard traits ---------------------------------------------------------------------------------
+
+impl<const C: usize> ops::Deref for StaticString<C> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        return self.as_str();
+    }
+}
+
+impl<const C: usize> ops::DerefMut for StaticString<C> {
+    fn deref_mut(&mut self) -> &mut str {
+        return self.as_mut_str();
+    }
+}
+
+impl<const C: usize> Clone for StaticString<C> {
+    fn clone(&self) -> Self {
+        return Self { vec: self.vec.clone() };
+    }
+}
+
+impl<const C: usize> fmt::Debug for StaticString<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return fmt::Debug::fmt(self.as_str(), f);
+    }
+}
+
+impl<const C: usize> fmt::Display for StaticString<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return fmt::Display::fmt(self.as_str(), f);
+    }
+}
+
+impl<const C: usize> fmt::Write for StaticString<C> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        return self.try_push_str(s).map_err(|_| fmt::Error);
+    }
+}
+
+impl<const C: usize, const D: usize> PartialEq<StaticString<D>> for StaticString<C> {
+    fn eq(&self, other: &StaticString<D>) -> bool {
+        return self.as_str() == other.as_str();
+    }
+}
+
+impl<const C: usize> Eq for StaticString<C> {}
+
+impl<const C: usize> PartialEq<str> for StaticString<C> {
+    fn eq(&self, other: &str) -> bool {
+        return self.as_str() == other;
+    }
+}
+
+impl<const C: usize> PartialEq<&str> for StaticString<C> {
+    fn eq(&self, other: &&str) -> bool {
+        return self.as_str() == *other;
+    }
+}
+
+// =================================================================================================
+// Tests
+// =================================================================================================
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use core::fmt::Write;
+    use std::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn push_and_pop() {
+        let mut s = StaticString::<8>::new();
+        assert_eq!(s.capacity(), 8);
+
+        s.push('a');
+        s.push('é');
+        s.push_str("bc");
+        assert_eq!(s, "aébc");
+        assert_eq!(s.as_bytes().len(), 5);
+
+        assert_eq!(s.pop(), Some('c'));
+        assert_eq!(s.pop(), Some('b'));
+        assert_eq!(s.pop(), Some('é'));
+        assert_eq!(s.as_str(), "a");
+
+        s.clear();
+        assert_eq!(s.pop(), None);
+    }
+
+    #[test]
+    fn try_push_out_of_capacity() {
+        let mut s = StaticString::<4>::new();
+        s.push_str("abc");
+
+        assert!(s.try_push('é').is_err());
+        assert!(s.try_push_str("de").is_err());
+        assert_eq!(s, "abc");
+
+        assert!(s.try_push('d').is_ok());
+        assert_eq!(s, "abcd");
+    }
+
+    #[test]
+    fn truncate() {
+        let mut s = StaticString::<8>::new();
+        s.push_str("aéb");
+
+        s.truncate(10);
+        assert_eq!(s, "aéb");
+
+        s.truncate(3);
+        assert_eq!(s, "aé");
+    }
+
+    #[test]
+    #[should_panic(expected = "Length is not on a char boundary.")]
+    fn truncate_inside_char() {
+        let mut s = StaticString::<8>::new();
+        s.push_str("aé");
+        s.truncate(2);
+    }
+
+    #[test]
+    fn traits() {
+        let mut s = StaticString::<16>::default();
+        let name = "x";
+        write!(s, "{}-{}", 1, name).unwrap();
+
+        assert_ne!(s, StaticString::<4>::new());
+        assert_eq!(s.to_string(), "1-x");
+        assert_eq!(std::format!("{:?}", s), "\"1-x\"");
+        assert_eq!(s.len(), 3);
+
+        s.make_ascii_uppercase();
+        assert_eq!(s.clone(), s);
+        assert_eq!(s, "1-X");
+
+        let mut small = StaticString::<2>::new();
+        assert!(write!(small, "{}", 123).is_err());
+    }
+}
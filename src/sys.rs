@@ -16,20 +16,46 @@
 // limitations under the License.
 // =================================================================================================
 
-use crate::io;
+pub mod io {
+    /// The buffer of [`crate::io::IoSlice`], which is a plain byte slice.
+    #[derive(Copy, Clone)]
+    pub struct IoSlice<'a>(&'a [u8]);
+
+    impl<'a> IoSlice<'a> {
+        pub fn new(buf: &'a [u8]) -> IoSlice<'a> {
+            return IoSlice(buf);
+        }
+
+        pub fn as_slice(&self) -> &[u8] {
+            return self.0;
+        }
+    }
+
+    /// The buffer of [`crate::io::IoSliceMut`], which is a plain mutable byte slice.
+    pub struct IoSliceMut<'a>(&'a mut [u8]);
+
+    impl<'a> IoSliceMut<'a> {
+        pub fn new(buf: &'a mut [u8]) -> IoSliceMut<'a> {
+            return IoSliceMut(buf);
+        }
+
+        pub fn as_slice(&self) -> &[u8] {
+            return self.0;
+        }
+
+        pub fn as_mut_slice(&mut self) -> &mut [u8] {
+            return self.0;
+        }
+    }
+}
 
 pub mod os {
 
     pub fn errno() -> i32 {
         return 0;
     }
-
-    pub fn error_string(_errno: i32) -> &'static str {
-        // In the standard library, this function returns a `String` object.
-        return "";
-    }
 }
 
-pub fn decode_error_kind(_errno: i32) -> io::ErrorKind {
-    return io::ErrorKind::Other;
+pub fn decode_error_kind(_errno: i32) -> crate::io::ErrorKind {
+    return crate::io::ErrorKind::Other;
 }
@@ -0,0 +1,173 @@
+// =================================================================================================
+// Copyright (c) 2023 Viet-Hoa Do <doviethoa@doviethoa.com>
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// =================================================================================================
+
+use core::ptr;
+
+use crate::vec::{CommonVec, StaticVec};
+
+// =================================================================================================
+// Owned chunks of static vector
+// =================================================================================================
+
+/// An iterator over chunks of `N` elements of a [`StaticVec`], each moved into a new vector.
+///
+/// This struct is created by [`StaticVec::into_chunk_vecs`]. The last chunk might contain
+/// fewer than `N` elements. The elements that haven't been yielded are dropped
+/// together with the iterator.
+pub struct ChunkVecs<T, const C: usize, const N: usize> {
+    vec: StaticVec<T, C>,
+    pos: usize,
+    end: usize,
+}
+
+// Constructors and destructor ---------------------------------------------------------------------
+
+impl<T, const C: usize, const N: usize> ChunkVecs<T, C, N> {
+    fn new(mut vec: StaticVec<T, C>) -> Self {
+        let end = vec.len();
+
+        // The ownership of the elements is managed by the iterator from now on.
+        unsafe {
+            vec.set_len(0);
+        }
+
+        return Self { vec, pos: 0, end };
+    }
+}
+
+impl<T, const C: usize, const N: usize> Drop for ChunkVecs<T, C, N> {
+    fn drop(&mut self) {
+        let remaining = ptr::slice_from_raw_parts_mut(
+            unsafe { self.vec.as_mut_ptr().add(self.pos) },
+            self.end - self.pos,
+        );
+
+        unsafe {
+            ptr::drop_in_place(remaining);
+        }
+    }
+}
+
+// Implement `Iterator` trait ----------------------------------------------------------------------
+
+impl<T, const C: usize, const N: usize> Iterator for ChunkVecs<T, C, N> {
+    type Item = StaticVec<T, N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos == self.end {
+            return None;
+        }
+
+        let len = N.min(self.end - self.pos);
+        let mut chunk = StaticVec::<T, N>::new();
+
+        unsafe {
+            ptr::copy_nonoverlapping(self.vec.as_ptr().add(self.pos), chunk.as_mut_ptr(), len);
+            chunk.set_len(len);
+        }
+
+        self.pos += len;
+
+        return Some(chunk);
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let num_chunks = (self.end - self.pos + N - 1) / N;
+        return (num_chunks, Some(num_chunks));
+    }
+}
+
+// Creates chunk iterator from static vector -------------------------------------------------------
+
+impl<T, const C: usize> StaticVec<T, C> {
+    /// Consumes the vector and returns an iterator over chunks of `N` elements,
+    /// each moved into a new vector.
+    ///
+    /// The last chunk might contain fewer than `N` elements. Panics if `N` is 0.
+    pub fn into_chunk_vecs<const N: usize>(self) -> ChunkVecs<T, C, N> {
+        assert!(N != 0, "Chunk size must be non-zero.");
+        return ChunkVecs::new(self);
+    }
+}
+
+// =================================================================================================
+// Tests
+// =================================================================================================
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use crate::vec::testing::{values, DropCounter};
+
+    #[test]
+    fn into_chunk_vecs() {
+        let vec = StaticVec::<i32, 8>::from_array([1, 2, 3, 4, 5, 6, 7]);
+        let mut chunks = vec.into_chunk_vecs::<3>();
+
+        assert_eq!(chunks.size_hint(), (3, Some(3)));
+        assert_eq!(chunks.next().unwrap().as_slice(), [1, 2, 3]);
+        assert_eq!(chunks.next().unwrap().as_slice(), [4, 5, 6]);
+        assert_eq!(chunks.size_hint(), (1, Some(1)));
+        assert_eq!(chunks.next().unwrap().as_slice(), [7]);
+        assert!(chunks.next().is_none());
+        assert_eq!(chunks.size_hint(), (0, Some(0)));
+
+        assert!(StaticVec::<i32, 4>::new().into_chunk_vecs::<3>().next().is_none());
+    }
+
+    #[test]
+    fn into_chunk_vecs_moves_elements() {
+        let counter = DropCounter::new();
+        let vec = StaticVec::<_, 7>::from_fn(|i| counter.item(i as i32));
+
+        let chunks = vec.into_chunk_vecs::<3>().collect::<std::vec::Vec<_>>();
+        assert_eq!(
+            chunks.iter().map(values).collect::<std::vec::Vec<_>>(),
+            [std::vec![0, 1, 2], std::vec![3, 4, 5], std::vec![6]]
+        );
+        assert_eq!(counter.count(), 0);
+
+        drop(chunks);
+        assert_eq!(counter.count(), 7);
+    }
+
+    #[test]
+    fn into_chunk_vecs_drops_remaining_elements() {
+        let counter = DropCounter::new();
+        let vec = StaticVec::<_, 7>::from_fn(|i| counter.item(i as i32));
+        let mut chunks = vec.into_chunk_vecs::<3>();
+
+        let chunk = chunks.next().unwrap();
+        assert_eq!(values(&chunk), [0, 1, 2]);
+        assert_eq!(counter.count(), 0);
+
+        drop(chunks);
+        assert_eq!(counter.count(), 4);
+
+        drop(chunk);
+        assert_eq!(counter.count(), 7);
+    }
+
+    #[test]
+    #[should_panic(expected = "Chunk size must be non-zero.")]
+    fn into_chunk_vecs_zero_size() {
+        let _ = StaticVec::<i32, 4>::new().into_chunk_vecs::<0>();
+    }
+}
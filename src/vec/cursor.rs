@@ -0,0 +1,149 @@
+// =================================================================================================
+// Copyright (c) 2023 Viet-Hoa Do <doviethoa@doviethoa.com>
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// =================================================================================================
+
+use crate::vec::{CommonVec, StaticVec};
+
+// =================================================================================================
+// Static vector cursor
+// =================================================================================================
+
+/// A read position within the elements of a [`StaticVec`].
+///
+/// This struct is created by [`StaticVec::cursor`]. It lets parsers walk a buffer
+/// without keeping track of the index manually.
+#[derive(Clone, Debug)]
+pub struct StaticVecCursor<'a, T> {
+    data: &'a [T],
+    pos: usize,
+}
+
+// Constructors ------------------------------------------------------------------------------------
+
+impl<'a, T> StaticVecCursor<'a, T> {
+    fn new(data: &'a [T]) -> Self {
+        return Self { data, pos: 0 };
+    }
+}
+
+// Cursor methods ----------------------------------------------------------------------------------
+
+impl<'a, T> StaticVecCursor<'a, T> {
+    /// Returns the current position of the cursor.
+    pub fn position(&self) -> usize {
+        return self.pos;
+    }
+
+    /// Moves the cursor to position `pos`.
+    ///
+    /// The cursor doesn't go past the end of the vector.
+    pub fn set_position(&mut self, pos: usize) {
+        self.pos = pos.min(self.data.len());
+    }
+
+    /// Returns the element at the current position without advancing the cursor.
+    ///
+    /// If the end of the vector has been reached, returns [`None`].
+    pub fn peek(&self) -> Option<&'a T> {
+        return self.data.get(self.pos);
+    }
+
+    /// Advances the cursor by `n` elements.
+    ///
+    /// The cursor doesn't go past the end of the vector.
+    pub fn advance(&mut self, n: usize) {
+        self.pos += n.min(self.data.len() - self.pos);
+    }
+
+    /// Returns all the elements from the current position to the end of the vector.
+    pub fn remaining(&self) -> &'a [T] {
+        return &self.data[self.pos..];
+    }
+
+    /// Returns the next `n` elements and advances the cursor past them.
+    ///
+    /// If there are fewer than `n` elements remaining, returns all of them.
+    pub fn take(&mut self, n: usize) -> &'a [T] {
+        let remaining = self.remaining();
+        let n = n.min(remaining.len());
+
+        self.pos += n;
+
+        return &remaining[..n];
+    }
+}
+
+// Creates cursor from static vector ---------------------------------------------------------------
+
+impl<T, const C: usize> StaticVec<T, C> {
+    /// Returns a cursor at the beginning of the vector.
+    pub fn cursor(&self) -> StaticVecCursor<'_, T> {
+        return StaticVecCursor::new(self.as_slice());
+    }
+}
+
+// =================================================================================================
+// Tests
+// =================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn peek_and_advance() {
+        let vec = StaticVec::<u8, 4>::from_array([1, 2, 3]);
+        let mut cursor = vec.cursor();
+
+        assert_eq!(cursor.position(), 0);
+        assert_eq!(cursor.peek(), Some(&1));
+
+        cursor.advance(2);
+        assert_eq!(cursor.position(), 2);
+        assert_eq!(cursor.peek(), Some(&3));
+        assert_eq!(cursor.remaining(), [3]);
+
+        cursor.advance(5);
+        assert_eq!(cursor.position(), 3);
+        assert_eq!(cursor.peek(), None);
+        assert_eq!(cursor.remaining(), []);
+    }
+
+    #[test]
+    fn take() {
+        let vec = StaticVec::<u8, 4>::from_array([1, 2, 3]);
+        let mut cursor = vec.cursor();
+
+        assert_eq!(cursor.take(2), [1, 2]);
+        assert_eq!(cursor.position(), 2);
+        assert_eq!(cursor.take(2), [3]);
+        assert_eq!(cursor.take(1), []);
+        assert_eq!(cursor.position(), 3);
+    }
+
+    #[test]
+    fn set_position() {
+        let vec = StaticVec::<u8, 4>::from_array([1, 2, 3]);
+        let mut cursor = vec.cursor();
+
+        cursor.set_position(1);
+        assert_eq!(cursor.remaining(), [2, 3]);
+
+        cursor.set_position(9);
+        assert_eq!(cursor.position(), 3);
+    }
+}
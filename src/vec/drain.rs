@@ -0,0 +1,267 @@
+// =================================================================================================
+// Copyright (c) 2023 Viet-Hoa Do <doviethoa@doviethoa.com>
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// =================================================================================================
+
+use core::{marker::PhantomData, ptr};
+
+use crate::vec::CommonVec;
+
+// =================================================================================================
+// Draining iterator
+// =================================================================================================
+
+/// An iterator that removes a range of elements from a vector and yields them.
+///
+/// This struct is created by [`CommonVec::drain`]. When it's dropped, the elements
+/// that haven't been yielded are dropped and the elements after the range are shifted
+/// to close the gap.
+pub struct Drain<'a, T, V: CommonVec<T> + ?Sized> {
+    vec: &'a mut V,
+
+    // The elements in `pos..end` haven't been yielded yet.
+    pos: usize,
+    end: usize,
+
+    // The elements after the drained range, which are moved back when the iterator is dropped.
+    tail_start: usize,
+    tail_len: usize,
+
+    _marker: PhantomData<T>,
+}
+
+// Constructors and destructor ---------------------------------------------------------------------
+
+impl<'a, T, V: CommonVec<T> + ?Sized> Drain<'a, T, V> {
+    pub(super) fn new(vec: &'a mut V, start: usize, end: usize) -> Self {
+        let len = vec.len();
+
+        // The ownership of the drained range and the tail is managed by the iterator from now on.
+        unsafe {
+            vec.set_len(start);
+        }
+
+        return Self {
+            vec,
+            pos: start,
+            end,
+            tail_start: end,
+            tail_len: len - end,
+            _marker: PhantomData,
+        };
+    }
+}
+
+impl<'a, T, V: CommonVec<T> + ?Sized> Drop for Drain<'a, T, V> {
+    fn drop(&mut self) {
+        // The tail is moved back even if dropping one of the remaining elements panics.
+        struct MoveTail<'r, 'a, T, V: CommonVec<T> + ?Sized>(&'r mut Drain<'a, T, V>);
+
+        impl<'r, 'a, T, V: CommonVec<T> + ?Sized> Drop for MoveTail<'r, 'a, T, V> {
+            fn drop(&mut self) {
+                let drain = &mut *self.0;
+                let start = drain.vec.len();
+                let buf_ptr = drain.vec.as_mut_ptr();
+
+                unsafe {
+                    if drain.tail_start != start {
+                        ptr::copy(
+                            buf_ptr.add(drain.tail_start),
+                            buf_ptr.add(start),
+                            drain.tail_len,
+                        );
+                    }
+
+                    drain.vec.set_len(start + drain.tail_len);
+                }
+            }
+        }
+
+        let remaining = ptr::slice_from_raw_parts_mut(
+            unsafe { self.vec.as_mut_ptr().add(self.pos) },
+            self.end - self.pos,
+        );
+
+        self.pos = self.end;
+
+        let _guard = MoveTail(self);
+
+        unsafe {
+            ptr::drop_in_place(remaining);
+        }
+    }
+}
+
+// Implement iterator traits -----------------------------------------------------------------------
+
+impl<'a, T, V: CommonVec<T> + ?Sized> Iterator for Drain<'a, T, V> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos == self.end {
+            return None;
+        }
+
+        let item = unsafe { ptr::read(self.vec.as_ptr().add(self.pos)) };
+        self.pos += 1;
+
+        return Some(item);
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.pos;
+        return (len, Some(len));
+    }
+}
+
+impl<'a, T, V: CommonVec<T> + ?Sized> DoubleEndedIterator for Drain<'a, T, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.pos == self.end {
+            return None;
+        }
+
+        self.end -= 1;
+
+        return Some(unsafe { ptr::read(self.vec.as_ptr().add(self.end)) });
+    }
+}
+
+impl<'a, T, V: CommonVec<T> + ?Sized> ExactSizeIterator for Drain<'a, T, V> {}
+
+// =================================================================================================
+// Tests
+// =================================================================================================
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use core::mem;
+    use std::panic::{self, AssertUnwindSafe};
+    use std::vec::Vec;
+
+    use crate::vec::testing::{values, DropCounter, Tracked};
+    use crate::vec::{CommonVec, StaticVec};
+
+    fn tracked_vec(counter: &DropCounter) -> StaticVec<Tracked, 8> {
+        let mut vec = StaticVec::new();
+
+        for value in 0..6 {
+            vec.push(counter.item(value));
+        }
+
+        return vec;
+    }
+
+    #[test]
+    fn middle_range() {
+        let mut vec = StaticVec::<i32, 8>::from_array([0, 1, 2, 3, 4, 5]);
+
+        assert_eq!(vec.drain(1..4).collect::<Vec<_>>(), [1, 2, 3]);
+        assert_eq!(vec.as_slice(), [0, 4, 5]);
+    }
+
+    #[test]
+    fn to_the_end_and_from_the_beginning() {
+        let mut vec = StaticVec::<i32, 8>::from_array([0, 1, 2, 3, 4, 5]);
+
+        assert_eq!(vec.drain(4..).rev().collect::<Vec<_>>(), [5, 4]);
+        assert_eq!(vec.drain(..=1).collect::<Vec<_>>(), [0, 1]);
+        assert_eq!(vec.as_slice(), [2, 3]);
+    }
+
+    #[test]
+    fn empty_range() {
+        let mut vec = StaticVec::<i32, 8>::from_array([0, 1, 2]);
+
+        let mut drain = vec.drain(2..2);
+        assert_eq!(drain.len(), 0);
+        assert_eq!(drain.next(), None);
+        drop(drain);
+
+        assert_eq!(vec.as_slice(), [0, 1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index is out-of-range.")]
+    fn out_of_range() {
+        let mut vec = StaticVec::<i32, 8>::from_array([0, 1, 2]);
+        vec.drain(1..4);
+    }
+
+    #[test]
+    fn partial_consumption_drops_each_element_once() {
+        let counter = DropCounter::new();
+        let mut vec = tracked_vec(&counter);
+
+        let mut drain = vec.drain(1..5);
+        let first = drain.next().unwrap();
+        let last = drain.next_back().unwrap();
+        assert_eq!((first.value, last.value), (1, 4));
+
+        drop(drain);
+        assert_eq!(counter.count(), 2);
+        assert_eq!(values(&vec), [0, 5]);
+
+        drop(first);
+        drop(last);
+        drop(vec);
+        assert_eq!(counter.count(), 6);
+    }
+
+    #[test]
+    fn forgotten_drain_leaves_valid_length() {
+        let counter = DropCounter::new();
+        let mut vec = tracked_vec(&counter);
+
+        let mut drain = vec.drain(2..4);
+        drain.next();
+        mem::forget(drain);
+
+        // The drained range and the tail are leaked, but the head is still valid.
+        assert_eq!(values(&vec), [0, 1]);
+        assert_eq!(counter.count(), 1);
+
+        vec.push(counter.item(9));
+        assert_eq!(values(&vec), [0, 1, 9]);
+
+        drop(vec);
+        assert_eq!(counter.count(), 4);
+    }
+
+    #[test]
+    fn panic_in_element_drop_moves_tail_back() {
+        struct PanicOnDrop(bool);
+
+        impl Drop for PanicOnDrop {
+            fn drop(&mut self) {
+                if self.0 {
+                    panic!("Element panics on drop.");
+                }
+            }
+        }
+
+        let mut vec = StaticVec::<_, 8>::new();
+
+        for i in 0..5 {
+            vec.push(PanicOnDrop(i == 2));
+        }
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| drop(vec.drain(1..3))));
+        assert!(result.is_err());
+        assert_eq!(vec.len(), 3);
+    }
+}
@@ -0,0 +1,430 @@
+// =================================================================================================
+// Copyright (c) 2023 Viet-Hoa Do <doviethoa@doviethoa.com>
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// =================================================================================================
+
+extern crate alloc;
+
+use alloc::alloc::{self as heap, Layout};
+use alloc::vec::Vec;
+use core::{mem, ptr, ptr::NonNull};
+
+use crate::vec::{CommonVec, TryReserveError, TryReserveErrorKind};
+
+// =================================================================================================
+// Heap allocated vector
+// =================================================================================================
+
+/// A contiguous growable array of type `T` allocated on the heap.
+///
+/// The buffer grows by doubling its capacity when more space is needed
+/// and is freed when the vector is dropped.
+pub struct HeapVec<T> {
+    ptr: NonNull<T>,
+    cap: usize,
+    len: usize,
+}
+
+unsafe impl<T: Send> Send for HeapVec<T> {}
+unsafe impl<T: Sync> Sync for HeapVec<T> {}
+
+// Constructors and destructor ---------------------------------------------------------------------
+
+impl<T> HeapVec<T> {
+    /// Constructs a new, empty `HeapVec<T>` without allocating.
+    pub const fn new() -> Self {
+        return Self { ptr: NonNull::dangling(), cap: 0, len: 0 };
+    }
+
+    /// Constructs a new, empty `HeapVec<T>` with the capacity of at least `capacity` elements.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut vec = Self::new();
+        vec.reserve_exact(capacity);
+
+        return vec;
+    }
+}
+
+impl<T> Default for HeapVec<T> {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl<T> Drop for HeapVec<T> {
+    fn drop(&mut self) {
+        self.clear();
+        self.reallocate(0).unwrap();
+    }
+}
+
+// Common vector methods ---------------------------------------------------------------------------
+
+impl<T> CommonVec<T> for HeapVec<T> {
+    fn capacity(&self) -> usize {
+        if mem::size_of::<T>() == 0 {
+            return usize::MAX;
+        }
+
+        return self.cap;
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let required =
+            self.len.checked_add(additional).ok_or(TryReserveErrorKind::CapacityOverflow)?;
+
+        if required <= self.capacity() {
+            return Ok(());
+        }
+
+        return self.reallocate(required.max(self.cap.saturating_mul(2)));
+    }
+
+    fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let required =
+            self.len.checked_add(additional).ok_or(TryReserveErrorKind::CapacityOverflow)?;
+
+        if required <= self.capacity() {
+            return Ok(());
+        }
+
+        return self.reallocate(required);
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to(0);
+    }
+
+    fn shrink_to(&mut self, min_capacity: usize) {
+        let new_cap = self.len.max(min_capacity);
+
+        if new_cap < self.capacity() {
+            self.reallocate(new_cap).unwrap();
+        }
+    }
+
+    fn as_ptr(&self) -> *const T {
+        return self.ptr.as_ptr();
+    }
+
+    fn as_mut_ptr(&mut self) -> *mut T {
+        return self.ptr.as_ptr();
+    }
+
+    unsafe fn set_len(&mut self, new_len: usize) {
+        debug_assert!(new_len <= self.capacity());
+        self.len = new_len;
+    }
+
+    fn len(&self) -> usize {
+        return self.len;
+    }
+}
+
+// Buffer management -------------------------------------------------------------------------------
+
+impl<T> HeapVec<T> {
+    /// Changes the capacity of the buffer to exactly `new_cap` elements.
+    ///
+    /// The buffer is freed if `new_cap` is 0. Zero-sized elements never need a buffer.
+    /// `new_cap` must not be less than the number of elements.
+    fn reallocate(&mut self, new_cap: usize) -> Result<(), TryReserveError> {
+        debug_assert!(new_cap >= self.len);
+
+        if mem::size_of::<T>() == 0 || new_cap == self.cap {
+            return Ok(());
+        }
+
+        let new_layout =
+            Layout::array::<T>(new_cap).map_err(|_| TryReserveErrorKind::CapacityOverflow)?;
+
+        let new_ptr = unsafe {
+            if self.cap == 0 {
+                heap::alloc(new_layout)
+            } else {
+                let old_layout = Layout::array::<T>(self.cap).unwrap();
+
+                if new_cap == 0 {
+                    heap::dealloc(self.ptr.as_ptr() as *mut u8, old_layout);
+                    ptr::null_mut()
+                } else {
+                    heap::realloc(self.ptr.as_ptr() as *mut u8, old_layout, new_layout.size())
+                }
+            }
+        };
+
+        if new_cap == 0 {
+            self.ptr = NonNull::dangling();
+        } else {
+            self.ptr = NonNull::new(new_ptr as *mut T).ok_or(TryReserveErrorKind::AllocError)?;
+        }
+
+        self.cap = new_cap;
+
+        return Ok(());
+    }
+}
+
+// =================================================================================================
+// Standard vector
+// =================================================================================================
+
+impl<T> CommonVec<T> for Vec<T> {
+    fn capacity(&self) -> usize {
+        return Vec::capacity(self);
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        return Vec::try_reserve(self, additional)
+            .map_err(|_| vec_reserve_error::<T>(Vec::len(self), additional));
+    }
+
+    fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        return Vec::try_reserve_exact(self, additional)
+            .map_err(|_| vec_reserve_error::<T>(Vec::len(self), additional));
+    }
+
+    fn shrink_to_fit(&mut self) {
+        Vec::shrink_to_fit(self);
+    }
+
+    fn shrink_to(&mut self, min_capacity: usize) {
+        Vec::shrink_to(self, min_capacity);
+    }
+
+    fn as_ptr(&self) -> *const T {
+        return Vec::as_ptr(self);
+    }
+
+    fn as_mut_ptr(&mut self) -> *mut T {
+        return Vec::as_mut_ptr(self);
+    }
+
+    unsafe fn set_len(&mut self, new_len: usize) {
+        Vec::set_len(self, new_len);
+    }
+
+    fn len(&self) -> usize {
+        return Vec::len(self);
+    }
+}
+
+/// Converts the error of reserving `additional` more elements for [`Vec`] of `len` elements.
+///
+/// The reason of the error isn't available from [`alloc::collections::TryReserveError`]
+/// in stable Rust, so it's the capacity overflow if the required capacity cannot even be
+/// represented by a memory layout and the allocation failure otherwise.
+fn vec_reserve_error<T>(len: usize, additional: usize) -> TryReserveError {
+    let required = len.checked_add(additional);
+
+    return match required.map(Layout::array::<T>) {
+        Some(Ok(_)) => TryReserveErrorKind::AllocError.into(),
+        _ => TryReserveErrorKind::CapacityOverflow.into(),
+    };
+}
+
+// =================================================================================================
+// Tests
+// =================================================================================================
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use crate::vec::testing::{check_common_vec, values, DropCounter};
+    use crate::vec::StaticVec;
+
+    #[test]
+    fn new_does_not_allocate() {
+        let vec = HeapVec::<i32>::new();
+        assert_eq!(vec.capacity(), 0);
+        assert_eq!(vec.len(), 0);
+    }
+
+    #[test]
+    fn grows_across_reallocations() {
+        let mut vec = HeapVec::new();
+        let mut capacities = Vec::new();
+
+        for value in 0..100 {
+            vec.push(value);
+
+            if capacities.last() != Some(&vec.capacity()) {
+                capacities.push(vec.capacity());
+            }
+        }
+
+        assert_eq!(capacities, [1, 2, 4, 8, 16, 32, 64, 128]);
+        assert!(vec.iter().copied().eq(0..100));
+    }
+
+    #[test]
+    fn with_capacity_and_shrink() {
+        let mut vec = HeapVec::with_capacity(10);
+        assert_eq!(vec.capacity(), 10);
+
+        vec.extend_from_iter(0..4);
+        vec.shrink_to(6);
+        assert_eq!(vec.capacity(), 6);
+
+        vec.shrink_to_fit();
+        assert_eq!(vec.capacity(), 4);
+        assert_eq!(vec.as_slice(), [0, 1, 2, 3]);
+
+        vec.clear();
+        vec.shrink_to_fit();
+        assert_eq!(vec.capacity(), 0);
+    }
+
+    #[test]
+    fn reserve_overflow() {
+        let mut vec = HeapVec::<u64>::new();
+        vec.push(1);
+
+        let error = vec.try_reserve(usize::MAX).unwrap_err();
+        assert_eq!(error.kind(), TryReserveErrorKind::CapacityOverflow);
+
+        let error = vec.try_reserve_exact(usize::MAX / 2).unwrap_err();
+        assert_eq!(error.kind(), TryReserveErrorKind::CapacityOverflow);
+
+        assert_eq!(vec.as_slice(), [1]);
+    }
+
+    #[test]
+    fn zero_sized_elements() {
+        let mut vec = HeapVec::new();
+        assert_eq!(vec.capacity(), usize::MAX);
+
+        for _ in 0..10 {
+            vec.push(());
+        }
+
+        assert_eq!(vec.len(), 10);
+        assert_eq!(vec.pop(), Some(()));
+    }
+
+    #[test]
+    fn drop_drops_each_element_once() {
+        let counter = DropCounter::new();
+        let mut vec = HeapVec::new();
+
+        for value in 0..10 {
+            vec.push(counter.item(value));
+        }
+
+        vec.truncate(7);
+        assert_eq!(counter.count(), 3);
+
+        vec.shrink_to_fit();
+        assert_eq!(values(vec.iter()), [0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(counter.count(), 3);
+
+        drop(vec);
+        assert_eq!(counter.count(), 10);
+    }
+
+    #[test]
+    fn common_vec_conformance() {
+        check_common_vec(HeapVec::new());
+        check_common_vec(Vec::new());
+    }
+
+    #[test]
+    fn vec_reserve_overflow() {
+        let mut vec = Vec::<u64>::new();
+        CommonVec::push(&mut vec, 1);
+
+        let error = CommonVec::try_reserve(&mut vec, usize::MAX).unwrap_err();
+        assert_eq!(error.kind(), TryReserveErrorKind::CapacityOverflow);
+
+        let error = CommonVec::try_reserve_exact(&mut vec, usize::MAX / 4).unwrap_err();
+        assert_eq!(error.kind(), TryReserveErrorKind::CapacityOverflow);
+
+        assert_eq!(CommonVec::as_slice(&vec), [1]);
+    }
+
+    #[test]
+    fn vec_common_methods_drop_elements() {
+        let counter = DropCounter::new();
+        let mut vec = Vec::new();
+
+        for value in 0..6 {
+            CommonVec::push(&mut vec, counter.item(value));
+        }
+
+        CommonVec::truncate(&mut vec, 4);
+        assert_eq!(counter.count(), 2);
+
+        CommonVec::retain(&mut vec, |x| x.value % 2 == 0);
+        assert_eq!(values(&vec), [0, 2]);
+        assert_eq!(counter.count(), 4);
+
+        CommonVec::clear(&mut vec);
+        assert_eq!(counter.count(), 6);
+    }
+
+    #[test]
+    fn vec_append_static_vec() {
+        let counter = DropCounter::new();
+        let mut vec = Vec::new();
+        let mut static_vec = StaticVec::<_, 8>::new();
+
+        for value in 0..2 {
+            vec.push(counter.item(value));
+            static_vec.push(counter.item(value + 2));
+        }
+
+        CommonVec::append(&mut vec, &mut static_vec);
+        assert_eq!(values(&vec), [0, 1, 2, 3]);
+        assert!(static_vec.is_empty());
+        assert_eq!(counter.count(), 0);
+
+        static_vec.push(counter.item(4));
+        CommonVec::append(&mut static_vec, &mut vec);
+        assert_eq!(values(&static_vec), [4, 0, 1, 2, 3]);
+        assert!(vec.is_empty());
+        assert_eq!(counter.count(), 0);
+
+        drop(vec);
+        assert_eq!(counter.count(), 0);
+
+        drop(static_vec);
+        assert_eq!(counter.count(), 5);
+    }
+
+    #[test]
+    fn vec_try_append_into_full_static_vec() {
+        let counter = DropCounter::new();
+        let mut static_vec = StaticVec::<_, 4>::new();
+        let mut vec = Vec::new();
+
+        for value in 0..3 {
+            static_vec.push(counter.item(value));
+            vec.push(counter.item(value + 3));
+        }
+
+        let error = static_vec.try_append(&mut vec).unwrap_err();
+        assert_eq!(error.kind(), TryReserveErrorKind::CapacityOverflow);
+        assert_eq!(values(&static_vec), [0, 1, 2]);
+        assert_eq!(values(&vec), [3, 4, 5]);
+        assert_eq!(counter.count(), 0);
+
+        drop(static_vec);
+        drop(vec);
+        assert_eq!(counter.count(), 6);
+    }
+}
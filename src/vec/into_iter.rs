@@ -0,0 +1,183 @@
+// =================================================================================================
+// Copyright (c) 2023 Viet-Hoa Do <doviethoa@doviethoa.com>
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// =================================================================================================
+
+use core::ptr;
+
+use crate::vec::{CommonVec, StaticVec};
+
+// =================================================================================================
+// Owning iterator of static vector
+// =================================================================================================
+
+/// An iterator that moves the elements out of a [`StaticVec`].
+///
+/// This struct is created by the `into_iter` method of [`StaticVec`]. The elements
+/// that haven't been yielded are dropped together with the iterator.
+pub struct IntoIter<T, const C: usize> {
+    vec: StaticVec<T, C>,
+    pos: usize,
+    end: usize,
+}
+
+// Constructors and destructor ---------------------------------------------------------------------
+
+impl<T, const C: usize> IntoIter<T, C> {
+    fn new(mut vec: StaticVec<T, C>) -> Self {
+        let end = vec.len();
+
+        // The ownership of the elements is managed by the iterator from now on.
+        unsafe {
+            vec.set_len(0);
+        }
+
+        return Self { vec, pos: 0, end };
+    }
+}
+
+impl<T, const C: usize> Drop for IntoIter<T, C> {
+    fn drop(&mut self) {
+        let remaining = ptr::slice_from_raw_parts_mut(
+            unsafe { self.vec.as_mut_ptr().add(self.pos) },
+            self.end - self.pos,
+        );
+
+        unsafe {
+            ptr::drop_in_place(remaining);
+        }
+    }
+}
+
+// Implement iterator traits -----------------------------------------------------------------------
+
+impl<T, const C: usize> Iterator for IntoIter<T, C> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos == self.end {
+            return None;
+        }
+
+        let item = unsafe { ptr::read(self.vec.as_ptr().add(self.pos)) };
+        self.pos += 1;
+
+        return Some(item);
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.pos;
+        return (len, Some(len));
+    }
+}
+
+impl<T, const C: usize> DoubleEndedIterator for IntoIter<T, C> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.pos == self.end {
+            return None;
+        }
+
+        self.end -= 1;
+
+        return Some(unsafe { ptr::read(self.vec.as_ptr().add(self.end)) });
+    }
+}
+
+impl<T, const C: usize> ExactSizeIterator for IntoIter<T, C> {}
+
+// Creates owning iterator from static vector ------------------------------------------------------
+
+impl<T, const C: usize> IntoIterator for StaticVec<T, C> {
+    type Item = T;
+    type IntoIter = IntoIter<T, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        return IntoIter::new(self);
+    }
+}
+
+// =================================================================================================
+// Tests
+// =================================================================================================
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::vec::Vec;
+
+    use super::*;
+    use crate::vec::testing::{DropCounter, Tracked};
+
+    fn tracked_vec(counter: &DropCounter) -> StaticVec<Tracked, 8> {
+        let mut vec = StaticVec::new();
+
+        for value in 0..5 {
+            vec.push(counter.item(value));
+        }
+
+        return vec;
+    }
+
+    #[test]
+    fn forward_and_backward() {
+        let vec = StaticVec::<i32, 8>::from_array([1, 2, 3, 4]);
+        assert_eq!(vec.clone().into_iter().collect::<Vec<_>>(), [1, 2, 3, 4]);
+        assert_eq!(vec.into_iter().rev().collect::<Vec<_>>(), [4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn exact_size() {
+        let mut iter = StaticVec::<i32, 8>::from_array([1, 2, 3]).into_iter();
+        assert_eq!(iter.len(), 3);
+
+        iter.next();
+        iter.next_back();
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn partial_consumption_drops_each_element_once() {
+        let counter = DropCounter::new();
+        let mut iter = tracked_vec(&counter).into_iter();
+
+        let first = iter.next().unwrap();
+        let last = iter.next_back().unwrap();
+        assert_eq!((first.value, last.value), (0, 4));
+        assert_eq!(counter.count(), 0);
+
+        drop(iter);
+        assert_eq!(counter.count(), 3);
+
+        drop(first);
+        drop(last);
+        assert_eq!(counter.count(), 5);
+    }
+
+    #[test]
+    fn full_consumption_drops_each_element_once() {
+        let counter = DropCounter::new();
+
+        for (i, item) in tracked_vec(&counter).into_iter().enumerate() {
+            assert_eq!(item.value, i as i32);
+        }
+
+        assert_eq!(counter.count(), 5);
+    }
+}
@@ -0,0 +1,77 @@
+// =================================================================================================
+// Copyright (c) 2023 Viet-Hoa Do <doviethoa@doviethoa.com>
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// =================================================================================================
+
+/// Creates a [`StaticVec`](crate::vec::StaticVec) containing the arguments.
+///
+/// Similar to `vec!` of `alloc` crate, there are two forms of this macro:
+///   - A list of elements, e.g. `static_vec![1, 2, 3]`. The capacity of the vector
+///     is the number of the elements. This form can be used in constant context.
+///   - An element and the number of repetitions, e.g. `static_vec![0u8; 16]`.
+///     The number must be a constant expression and becomes the capacity of the vector.
+///     The element must implement [`Clone`].
+#[macro_export]
+macro_rules! static_vec {
+    () => {
+        $crate::vec::StaticVec::<_, 0>::new()
+    };
+    ($elem:expr; $n:expr) => {{
+        let mut vec = $crate::vec::StaticVec::<_, { $n }>::new();
+        $crate::vec::CommonVec::push_n(&mut vec, $elem, $n);
+        vec
+    }};
+    ($($x:expr),+ $(,)?) => {
+        $crate::vec::StaticVec::from_exact_array([$($x),+])
+    };
+}
+
+// =================================================================================================
+// Tests
+// =================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use crate::vec::testing::{values, DropCounter};
+    use crate::vec::{CommonVec, StaticVec};
+
+    #[test]
+    fn list_form() {
+        const VEC: StaticVec<u8, 3> = static_vec![1, 2, 3,];
+
+        assert_eq!(VEC.as_slice(), [1, 2, 3]);
+        assert_eq!(VEC.capacity(), 3);
+
+        let empty: StaticVec<i32, 0> = static_vec![];
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn repeat_form() {
+        const N: usize = 4;
+
+        let vec = static_vec![7u16; N];
+        assert_eq!(vec.as_slice(), [7; 4]);
+        assert_eq!(vec.capacity(), 4);
+
+        let counter = DropCounter::new();
+        let vec = static_vec![counter.item(1); 3];
+        assert_eq!(values(&vec), [1, 1, 1]);
+
+        drop(vec);
+        assert_eq!(counter.count(), 3);
+    }
+}
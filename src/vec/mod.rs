@@ -16,6 +16,26 @@
 // limitations under the License.
 // =================================================================================================
 
+mod chunks;
+mod cursor;
+mod drain;
+#[cfg(feature = "alloc")]
+mod heap;
+mod into_iter;
+mod macros;
+#[cfg(feature = "alloc")]
+mod spill;
+#[cfg(test)]
+mod testing;
+#[allow(clippy::module_inception)]
 mod vec;
 
+pub use chunks::*;
+pub use cursor::*;
+pub use drain::*;
+#[cfg(feature = "alloc")]
+pub use heap::*;
+pub use into_iter::*;
+#[cfg(feature = "alloc")]
+pub use spill::*;
 pub use vec::*;
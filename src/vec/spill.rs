@@ -0,0 +1,243 @@
+// =================================================================================================
+// Copyright (c) 2023 Viet-Hoa Do <doviethoa@doviethoa.com>
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// =================================================================================================
+
+use core::ptr;
+
+use crate::vec::{CommonVec, HeapVec, StaticVec, TryReserveError, TryReserveErrorKind};
+
+// =================================================================================================
+// Spillable vector
+// =================================================================================================
+
+/// A contiguous growable array of type `T` that stores up to `C` elements inline
+/// and spills to the heap when more space is needed.
+///
+/// Once spilled, the elements stay on the heap until the vector is dropped.
+pub struct SpillVec<T, const C: usize> {
+    storage: Storage<T, C>,
+}
+
+enum Storage<T, const C: usize> {
+    Inline(StaticVec<T, C>),
+    Heap(HeapVec<T>),
+}
+
+// Constructors ------------------------------------------------------------------------------------
+
+impl<T, const C: usize> SpillVec<T, C> {
+    /// Constructs a new, empty `SpillVec<T, C>` that stores the elements inline.
+    pub const fn new() -> Self {
+        return Self { storage: Storage::Inline(StaticVec::new()) };
+    }
+
+    /// Constructs a new, empty `SpillVec<T, C>` with the capacity of at least
+    /// `capacity` elements.
+    ///
+    /// The vector is spilled to the heap right away if `capacity` is greater than `C`.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut vec = Self::new();
+        vec.reserve(capacity);
+
+        return vec;
+    }
+}
+
+impl<T, const C: usize> Default for SpillVec<T, C> {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+// Accessors ---------------------------------------------------------------------------------------
+
+impl<T, const C: usize> SpillVec<T, C> {
+    /// Returns whether the elements have been moved to the heap.
+    pub fn is_spilled(&self) -> bool {
+        return matches!(self.storage, Storage::Heap(_));
+    }
+}
+
+// Common vector methods ---------------------------------------------------------------------------
+
+impl<T, const C: usize> CommonVec<T> for SpillVec<T, C> {
+    fn capacity(&self) -> usize {
+        return match &self.storage {
+            Storage::Inline(vec) => vec.capacity(),
+            Storage::Heap(vec) => vec.capacity(),
+        };
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let inline = match &mut self.storage {
+            Storage::Inline(vec) => vec,
+            Storage::Heap(vec) => return vec.try_reserve(additional),
+        };
+
+        let len = inline.len();
+        let required = len.checked_add(additional).ok_or(TryReserveErrorKind::CapacityOverflow)?;
+
+        if required <= C {
+            return Ok(());
+        }
+
+        let mut heap = HeapVec::<T>::new();
+        heap.try_reserve_exact(required.max(C.saturating_mul(2)))?;
+
+        // The elements are moved to the heap, hence they must not be dropped by the inline vector.
+        unsafe {
+            ptr::copy_nonoverlapping(inline.as_ptr(), heap.as_mut_ptr(), len);
+            inline.set_len(0);
+            heap.set_len(len);
+        }
+
+        self.storage = Storage::Heap(heap);
+
+        return Ok(());
+    }
+
+    fn shrink_to_fit(&mut self) {
+        if let Storage::Heap(vec) = &mut self.storage {
+            vec.shrink_to_fit();
+        }
+    }
+
+    fn shrink_to(&mut self, min_capacity: usize) {
+        if let Storage::Heap(vec) = &mut self.storage {
+            vec.shrink_to(min_capacity);
+        }
+    }
+
+    fn as_ptr(&self) -> *const T {
+        return match &self.storage {
+            Storage::Inline(vec) => vec.as_ptr(),
+            Storage::Heap(vec) => vec.as_ptr(),
+        };
+    }
+
+    fn as_mut_ptr(&mut self) -> *mut T {
+        return match &mut self.storage {
+            Storage::Inline(vec) => vec.as_mut_ptr(),
+            Storage::Heap(vec) => vec.as_mut_ptr(),
+        };
+    }
+
+    unsafe fn set_len(&mut self, new_len: usize) {
+        match &mut self.storage {
+            Storage::Inline(vec) => vec.set_len(new_len),
+            Storage::Heap(vec) => vec.set_len(new_len),
+        }
+    }
+
+    fn len(&self) -> usize {
+        return match &self.storage {
+            Storage::Inline(vec) => vec.len(),
+            Storage::Heap(vec) => vec.len(),
+        };
+    }
+}
+
+// =================================================================================================
+// Tests
+// =================================================================================================
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::vec::Vec;
+
+    use super::*;
+    use crate::vec::testing::{check_common_vec, values, DropCounter};
+
+    #[test]
+    fn stays_inline_up_to_capacity() {
+        let mut vec = SpillVec::<i32, 4>::new();
+
+        for value in 0..4 {
+            vec.push(value);
+        }
+
+        assert!(!vec.is_spilled());
+        assert_eq!(vec.capacity(), 4);
+        assert_eq!(vec.as_slice(), [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn spills_past_capacity() {
+        let mut vec = SpillVec::<i32, 4>::new();
+        vec.extend_from_iter(0..4);
+
+        vec.push(4);
+        assert!(vec.is_spilled());
+        assert_eq!(vec.capacity(), 8);
+        assert_eq!(vec.as_slice(), [0, 1, 2, 3, 4]);
+
+        // The elements stay on the heap even if they fit inline again.
+        vec.truncate(1);
+        vec.shrink_to_fit();
+        assert!(vec.is_spilled());
+        assert_eq!(vec.as_slice(), [0]);
+    }
+
+    #[test]
+    fn with_capacity() {
+        let vec = SpillVec::<i32, 4>::with_capacity(4);
+        assert!(!vec.is_spilled());
+
+        let vec = SpillVec::<i32, 4>::with_capacity(20);
+        assert!(vec.is_spilled());
+        assert_eq!(vec.capacity(), 20);
+    }
+
+    #[test]
+    fn spill_moves_each_element_once() {
+        let counter = DropCounter::new();
+        let mut vec = SpillVec::<_, 2>::new();
+
+        for value in 0..5 {
+            vec.push(counter.item(value));
+        }
+
+        assert!(vec.is_spilled());
+        assert_eq!(values(vec.iter()), [0, 1, 2, 3, 4]);
+        assert_eq!(counter.count(), 0);
+
+        drop(vec);
+        assert_eq!(counter.count(), 5);
+    }
+
+    #[test]
+    fn drop_inline() {
+        let counter = DropCounter::new();
+        let mut vec = SpillVec::<_, 4>::new();
+        vec.push(counter.item(0));
+        vec.push(counter.item(1));
+
+        drop(vec);
+        assert_eq!(counter.count(), 2);
+    }
+
+    #[test]
+    fn common_vec_conformance() {
+        check_common_vec(SpillVec::<_, 8>::new());
+        check_common_vec(SpillVec::<_, 2>::new());
+
+        let vec = SpillVec::<i32, 0>::new();
+        assert_eq!(vec.as_slice(), Vec::<i32>::new());
+    }
+}
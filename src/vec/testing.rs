@@ -0,0 +1,126 @@
+// =================================================================================================
+// Copyright (c) 2023 Viet-Hoa Do <doviethoa@doviethoa.com>
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// =================================================================================================
+
+//! Helpers shared by the tests of the vector types.
+
+extern crate std;
+
+use core::cell::Cell;
+use std::rc::Rc;
+use std::vec::Vec;
+
+use crate::vec::CommonVec;
+
+/// Counts how many times the elements created from it have been dropped.
+#[derive(Clone, Default)]
+pub struct DropCounter(Rc<Cell<usize>>);
+
+impl DropCounter {
+    pub fn new() -> Self {
+        return Self::default();
+    }
+
+    /// Creates a new element that increments the counter when it's dropped.
+    pub fn item(&self, value: i32) -> Tracked {
+        return Tracked { value, counter: self.0.clone(), panic_on_clone: false };
+    }
+
+    /// Creates a new element like [`DropCounter::item`] whose `clone` panics.
+    pub fn panicking_item(&self, value: i32) -> Tracked {
+        return Tracked { value, counter: self.0.clone(), panic_on_clone: true };
+    }
+
+    /// Returns the number of the dropped elements.
+    pub fn count(&self) -> usize {
+        return self.0.get();
+    }
+}
+
+/// An element that increments its [`DropCounter`] when it's dropped.
+#[derive(Debug)]
+pub struct Tracked {
+    pub value: i32,
+    counter: Rc<Cell<usize>>,
+    panic_on_clone: bool,
+}
+
+impl Clone for Tracked {
+    fn clone(&self) -> Self {
+        if self.panic_on_clone {
+            panic!("Element panics on clone.");
+        }
+
+        return Self { value: self.value, counter: self.counter.clone(), panic_on_clone: false };
+    }
+}
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        self.counter.set(self.counter.get() + 1);
+    }
+}
+
+impl PartialEq for Tracked {
+    fn eq(&self, other: &Self) -> bool {
+        return self.value == other.value;
+    }
+}
+
+/// Returns the values of the tracked elements in order.
+pub fn values<'a>(items: impl IntoIterator<Item = &'a Tracked>) -> Vec<i32> {
+    return items.into_iter().map(|item| item.value).collect();
+}
+
+/// Checks the common vector methods against the expected results.
+///
+/// The vector must be empty and able to hold at least 8 elements.
+pub fn check_common_vec<V: CommonVec<i32>>(mut vec: V) {
+    assert!(vec.is_empty());
+
+    vec.extend_from_iter([3, 1, 4, 1, 5]);
+    assert_eq!(vec.as_slice(), [3, 1, 4, 1, 5]);
+
+    vec.insert(0, 9);
+    vec.push(2);
+    assert_eq!(vec.as_slice(), [9, 3, 1, 4, 1, 5, 2]);
+
+    assert_eq!(vec.remove(1), 3);
+    assert_eq!(vec.swap_remove(0), 9);
+    assert_eq!(vec.as_slice(), [2, 1, 4, 1, 5]);
+
+    vec.retain(|x| *x != 4);
+    vec.dedup();
+    assert_eq!(vec.as_slice(), [2, 1, 5]);
+
+    assert!(vec.contains(&5));
+    assert!(vec.starts_with(&[2, 1]));
+    assert!(vec.ends_with(&[5]));
+
+    assert_eq!(vec.drain(..2).collect::<Vec<_>>(), [2, 1]);
+    assert_eq!(vec.pop(), Some(5));
+    assert_eq!(vec.pop(), None);
+
+    vec.resize_with(8, || 7);
+    assert_eq!(vec.as_slice(), [7; 8]);
+
+    vec.truncate(3);
+    assert_eq!(vec.len(), 3);
+
+    vec.clear();
+    assert!(vec.is_empty());
+}
@@ -16,7 +16,10 @@
 // limitations under the License.
 // =================================================================================================
 
-use core::{mem, ptr, slice};
+use core::{borrow, cmp, fmt, hash, mem, ops, ptr, slice, str};
+
+use crate::error;
+use crate::vec::Drain;
 
 // =================================================================================================
 // Common vector
@@ -56,6 +59,20 @@ pub trait CommonVec<T> {
         return self.try_reserve(additional);
     }
 
+    /// Reserves a capacity of at least `total` elements.
+    ///
+    /// If the vector already contains `total` elements or more, does nothing.
+    fn reserve_total(&mut self, total: usize) {
+        self.try_reserve_total(total).unwrap();
+    }
+
+    /// Tries to reserves a capacity of at least `total` elements.
+    ///
+    /// If the vector already contains `total` elements or more, does nothing.
+    fn try_reserve_total(&mut self, total: usize) -> Result<(), TryReserveError> {
+        return self.try_reserve(total.saturating_sub(self.len()));
+    }
+
     /// Shrinks the capacity of the vector as much as possible.
     fn shrink_to_fit(&mut self) {}
 
@@ -81,6 +98,38 @@ pub trait CommonVec<T> {
         }
     }
 
+    /// Shortens the vector to the first `len` elements and drops the rest from the last
+    /// to the first.
+    ///
+    /// If the current number of elements is less than `len`, does nothing.
+    fn truncate_lifo(&mut self, len: usize) {
+        let mut cur_len = self.len();
+
+        // The length is updated before each element is dropped, so that the element
+        // is not dropped again if its destructor panics.
+        while cur_len > len {
+            cur_len -= 1;
+
+            unsafe {
+                self.set_len(cur_len);
+                ptr::drop_in_place(self.as_mut_ptr().add(cur_len));
+            }
+        }
+    }
+
+    /// Shortens the vector right before the first element `e` for which `f(&e)` returns `true`
+    /// and drops that element and the rest.
+    ///
+    /// If there is no such element, does nothing.
+    fn truncate_at<F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        if let Some(len) = self.as_slice().iter().position(f) {
+            self.truncate(len);
+        }
+    }
+
     /// Returns a slice that contains the entire vector.
     fn as_slice(&self) -> &[T] {
         return unsafe { slice::from_raw_parts(self.as_ptr(), self.len()) };
@@ -91,6 +140,181 @@ pub trait CommonVec<T> {
         return unsafe { slice::from_raw_parts_mut(self.as_mut_ptr(), self.len()) };
     }
 
+    /// Returns an iterator over the elements of the vector.
+    fn iter(&self) -> slice::Iter<'_, T> {
+        return self.as_slice().iter();
+    }
+
+    /// Returns an iterator that allows modifying each element of the vector.
+    fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        return self.as_mut_slice().iter_mut();
+    }
+
+    /// Applies `f` to every element of the vector in place.
+    fn map_in_place<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        self.as_mut_slice().iter_mut().for_each(f);
+    }
+
+    /// Applies the fallible `f` to every element of the vector in place
+    /// until it returns an error.
+    ///
+    /// The first error is returned. The elements before the failed one have already been
+    /// transformed and are not rolled back, while the failed element is left
+    /// in whatever state `f` put it in.
+    fn try_map_in_place<E, F>(&mut self, f: F) -> Result<(), E>
+    where
+        F: FnMut(&mut T) -> Result<(), E>,
+    {
+        return self.as_mut_slice().iter_mut().try_for_each(f);
+    }
+
+    /// Clones as many elements of the vector as possible to the beginning of `dst`
+    /// and returns the number of cloned elements.
+    fn clone_into_slice(&self, dst: &mut [T]) -> usize
+    where
+        T: Clone,
+    {
+        let src = self.as_slice();
+        let num_clone = src.len().min(dst.len());
+
+        dst[..num_clone].clone_from_slice(&src[..num_clone]);
+
+        return num_clone;
+    }
+
+    /// Returns `true` if the vector contains an element equal to `x`.
+    fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        return self.as_slice().contains(x);
+    }
+
+    /// Returns `true` if `needle` is a prefix of the vector.
+    ///
+    /// An empty `needle` is a prefix of any vector.
+    fn starts_with(&self, needle: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        return self.as_slice().starts_with(needle);
+    }
+
+    /// Returns `true` if `needle` is a suffix of the vector.
+    ///
+    /// An empty `needle` is a suffix of any vector.
+    fn ends_with(&self, needle: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        return self.as_slice().ends_with(needle);
+    }
+
+    /// Searches the sorted vector for `x` using binary search.
+    ///
+    /// If it's found, returns `Ok` with the position of a matching element.
+    /// Otherwise, returns `Err` with the position where `x` could be inserted
+    /// while keeping the vector sorted.
+    fn binary_search(&self, x: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        return self.as_slice().binary_search(x);
+    }
+
+    /// Searches the sorted vector using binary search with the comparator function `f`,
+    /// which returns the ordering of an element relative to the target.
+    ///
+    /// See [`binary_search`] for the return value.
+    fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> cmp::Ordering,
+    {
+        return self.as_slice().binary_search_by(f);
+    }
+
+    /// Searches the vector sorted by the keys extracted by `f` for the key `b`
+    /// using binary search.
+    ///
+    /// See [`binary_search`] for the return value.
+    fn binary_search_by_key<B, F>(&self, b: &B, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> B,
+        B: Ord,
+    {
+        return self.as_slice().binary_search_by_key(b, f);
+    }
+
+    /// Returns the length of the longest common prefix of the vector and `other`.
+    fn common_prefix_len(&self, other: &[T]) -> usize
+    where
+        T: PartialEq,
+    {
+        return self.as_slice().iter().zip(other).take_while(|(a, b)| a == b).count();
+    }
+
+    /// Returns the smallest and the largest elements of the vector, or `None` if it's empty.
+    ///
+    /// Both are found in a single pass by comparing the elements in pairs, which takes about
+    /// `3 * len / 2` comparisons rather than `2 * len`. If several elements are equally minimum,
+    /// the first one is returned. If several elements are equally maximum, the last one is returned.
+    fn min_max(&self) -> Option<(&T, &T)>
+    where
+        T: Ord,
+    {
+        let elements = self.as_slice();
+
+        let (mut min, mut max, rest) = match elements {
+            [] => return None,
+            [first, rest @ ..] if rest.len() % 2 == 0 => (first, first, rest),
+            [a, b, rest @ ..] => {
+                if b < a {
+                    (b, a, rest)
+                } else {
+                    (a, b, rest)
+                }
+            }
+            _ => unreachable!(),
+        };
+
+        for pair in rest.chunks_exact(2) {
+            let (small, large) =
+                if pair[1] < pair[0] { (&pair[1], &pair[0]) } else { (&pair[0], &pair[1]) };
+
+            if small < min {
+                min = small;
+            }
+
+            if large >= max {
+                max = large;
+            }
+        }
+
+        return Some((min, max));
+    }
+
+    /// Folds every element of the vector into an accumulator by applying `f`, front to back.
+    ///
+    /// This is equivalent to `Iterator::fold` but walks the buffer directly.
+    fn fold<B, F>(&self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, &T) -> B,
+    {
+        let elements = self.as_slice();
+        let mut acc = init;
+        let mut i = 0usize;
+
+        while i < elements.len() {
+            acc = f(acc, &elements[i]);
+            i += 1;
+        }
+
+        return acc;
+    }
+
     /// Returns a raw pointer to the vector's buffer.
     ///
     /// If the buffer hasn't been allocated, returns a dangling raw pointer.
@@ -102,6 +326,11 @@ pub trait CommonVec<T> {
     fn as_mut_ptr(&mut self) -> *mut T;
 
     /// Sets the length of the vector to `new_len`.
+    ///
+    /// # Safety
+    ///
+    /// `new_len` must not be greater than [`capacity`] and all the elements
+    /// in the range `0..new_len` must be initialized.
     unsafe fn set_len(&mut self, new_len: usize);
 
     /// Removes the element at position `index` and returns it.
@@ -129,6 +358,26 @@ pub trait CommonVec<T> {
         }
     }
 
+    /// Replaces the element at position `index` by `value` and returns the previous element.
+    fn replace(&mut self, index: usize, value: T) -> T {
+        if index >= self.len() {
+            panic!("Index is out-of-range.");
+        }
+
+        return mem::replace(&mut self.as_mut_slice()[index], value);
+    }
+
+    /// Rotates the vector in place so that the element at position `index` becomes the first one.
+    ///
+    /// The elements before position `index` are moved to the back in the same order.
+    fn rotate_to_front(&mut self, index: usize) {
+        if index >= self.len() {
+            panic!("Index is out-of-range.");
+        }
+
+        self.as_mut_slice().rotate_left(index);
+    }
+
     /// Inserts the element at position `index`.
     ///
     /// All the elements at and after position `index` will be shifted one position to the right.
@@ -161,6 +410,47 @@ pub trait CommonVec<T> {
         }
     }
 
+    /// Inserts the element to the sorted vector while keeping it sorted
+    /// and returns the position of the element.
+    ///
+    /// `dups` determines what to do if the vector already contains an element equal to `value`.
+    /// If the vector is full or the duplicate is rejected, `value` is returned as the error.
+    fn insert_sorted(&mut self, value: T, dups: DupPolicy) -> Result<usize, T>
+    where
+        T: Ord,
+    {
+        match self.as_slice().binary_search(&value) {
+            Ok(index) => match dups {
+                DupPolicy::Allow => {
+                    if self.try_reserve(1).is_err() {
+                        return Err(value);
+                    }
+
+                    self.insert(index + 1, value);
+                    return Ok(index + 1);
+                }
+
+                DupPolicy::Reject => {
+                    return Err(value);
+                }
+
+                DupPolicy::Replace => {
+                    self.as_mut_slice()[index] = value;
+                    return Ok(index);
+                }
+            },
+
+            Err(index) => {
+                if self.try_reserve(1).is_err() {
+                    return Err(value);
+                }
+
+                self.insert(index, value);
+                return Ok(index);
+            }
+        }
+    }
+
     /// Removes the element at position `index` and returns it.
     ///
     /// All the elements after position `index` will be shifted one position to the left.
@@ -187,6 +477,31 @@ pub trait CommonVec<T> {
         }
     }
 
+    /// Swaps the elements in range `a` with the elements in range `b`.
+    ///
+    /// Panics if the ranges are out of the vector, overlap or have different lengths.
+    fn swap_ranges(&mut self, a: ops::Range<usize>, b: ops::Range<usize>) {
+        let len = self.len();
+
+        if a.start > a.end || b.start > b.end || a.end > len || b.end > len {
+            panic!("Index is out-of-range.");
+        }
+
+        if a.len() != b.len() {
+            panic!("Ranges have different lengths.");
+        }
+
+        if a.start < b.end && b.start < a.end {
+            panic!("Ranges overlap.");
+        }
+
+        let buf_ptr = self.as_mut_ptr();
+
+        unsafe {
+            ptr::swap_nonoverlapping(buf_ptr.add(a.start), buf_ptr.add(b.start), a.len());
+        }
+    }
+
     /// Returns only elements `e` for which `f(&e)` returns `true`.
     fn retain<F>(&mut self, mut f: F)
     where
@@ -200,50 +515,85 @@ pub trait CommonVec<T> {
     where
         F: FnMut(&mut T) -> bool,
     {
-        let len = self.len();
-        let buf_ptr = self.as_mut_ptr();
-
-        let mut i = 0usize;
+        // If `f` or the destructor of an element panics, the guard shifts the unprocessed elements
+        // to close the gap and fixes the length, so that no element is dropped twice or leaked.
+        struct Guard<'a, T, V: CommonVec<T> + ?Sized> {
+            vec: &'a mut V,
+            buf_ptr: *mut T,
+            processed: usize,
+            deleted: usize,
+            original_len: usize,
+        }
 
-        while i < len {
-            unsafe {
-                let curr_ptr = buf_ptr.add(i);
-                let is_retained = f(&mut *curr_ptr);
+        impl<'a, T, V: CommonVec<T> + ?Sized> Drop for Guard<'a, T, V> {
+            fn drop(&mut self) {
+                unsafe {
+                    if self.deleted > 0 {
+                        ptr::copy(
+                            self.buf_ptr.add(self.processed),
+                            self.buf_ptr.add(self.processed - self.deleted),
+                            self.original_len - self.processed,
+                        );
+                    }
 
-                if !is_retained {
-                    ptr::drop_in_place(curr_ptr);
-                    break;
+                    self.vec.set_len(self.original_len - self.deleted);
                 }
             }
+        }
 
-            i += 1;
+        let original_len = self.len();
+        let buf_ptr = self.as_mut_ptr();
+
+        // The elements are owned by the guard until it's dropped.
+        unsafe {
+            self.set_len(0);
         }
 
-        if i < len {
-            let mut new_len = i;
+        let mut guard = Guard { vec: self, buf_ptr, processed: 0, deleted: 0, original_len };
 
-            for i in i + 1..len {
-                unsafe {
-                    let curr_ptr = buf_ptr.add(i);
-                    let is_retained = f(&mut *curr_ptr);
+        while guard.processed < original_len {
+            unsafe {
+                let curr_ptr = buf_ptr.add(guard.processed);
+                let is_retained = f(&mut *curr_ptr);
 
-                    if is_retained {
+                guard.processed += 1;
+
+                if is_retained {
+                    if guard.deleted > 0 {
                         // REVISIT: If we can copy more than one elements at a time, it would be faster.
-                        let new_last_ptr = buf_ptr.add(new_len);
-                        ptr::copy_nonoverlapping(curr_ptr, new_last_ptr, 1);
-                        new_len += 1;
-                    } else {
-                        ptr::drop_in_place(curr_ptr);
+                        let new_ptr = buf_ptr.add(guard.processed - 1 - guard.deleted);
+                        ptr::copy_nonoverlapping(curr_ptr, new_ptr, 1);
                     }
+                } else {
+                    guard.deleted += 1;
+                    ptr::drop_in_place(curr_ptr);
                 }
             }
-
-            unsafe {
-                self.set_len(new_len);
-            }
         }
     }
 
+    /// Returns only elements `e` for which `f(&e)` returns `true`
+    /// and returns the number of removed elements.
+    fn retain_count<F>(&mut self, f: F) -> usize
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let len = self.len();
+        self.retain(f);
+
+        return len - self.len();
+    }
+
+    /// Removes consecutive repeated elements in the vector.
+    ///
+    /// If the vector is sorted, this removes all duplicates.
+    fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b);
+    }
+
     /// Removes all elements `e` in the vector that has the same `key(e)` value
     /// with the previous element.
     fn dedup_by_key<F, K>(&mut self, mut key: F)
@@ -299,6 +649,8 @@ pub trait CommonVec<T> {
                         let new_last_ptr = buf_ptr.add(new_len);
                         ptr::copy_nonoverlapping(curr_ptr, new_last_ptr, 1);
                         new_len += 1;
+
+                        prev_ptr = new_last_ptr;
                     }
                 }
             }
@@ -327,6 +679,95 @@ pub trait CommonVec<T> {
         }
     }
 
+    /// Pushes a new element to the end of the vector if there is spare capacity.
+    ///
+    /// Unlike [`push`], no storage is reserved. If the vector is full,
+    /// `value` is returned as the error.
+    fn push_within_capacity(&mut self, value: T) -> Result<(), T> {
+        let len = self.len();
+
+        if len >= self.capacity() {
+            return Err(value);
+        }
+
+        unsafe {
+            ptr::write(self.as_mut_ptr().add(len), value);
+            self.set_len(len + 1);
+        }
+
+        return Ok(());
+    }
+
+    /// Appends `n` copies of `value` to the back of the vector.
+    ///
+    /// The storage is reserved up front and `value` itself is moved into the last slot,
+    /// so only `n - 1` clones are made.
+    fn push_n(&mut self, value: T, n: usize)
+    where
+        T: Clone,
+    {
+        if n == 0 {
+            return;
+        }
+
+        let len = self.len();
+        self.reserve(n);
+
+        let buf_ptr = self.as_mut_ptr();
+
+        unsafe {
+            for i in 0..(n - 1) {
+                ptr::write(buf_ptr.add(len + i), value.clone());
+            }
+
+            ptr::write(buf_ptr.add(len + n - 1), value);
+            self.set_len(len + n);
+        }
+    }
+
+    /// Appends all the elements of `iter` to the back of the vector.
+    ///
+    /// The storage for the lower bound of the iterator's size hint is reserved up front.
+    fn extend_from_iter<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+
+        for item in iter {
+            self.push(item);
+        }
+    }
+
+    /// Inserts clones of all the elements in `src` to the front of the vector.
+    ///
+    /// All the existing elements will be shifted `src.len()` positions to the right at once.
+    fn prepend_slice(&mut self, src: &[T])
+    where
+        T: Clone,
+    {
+        let len = self.len();
+        let src_len = src.len();
+
+        self.reserve(src_len);
+
+        let buf_ptr = self.as_mut_ptr();
+
+        unsafe {
+            // The elements are only kept in the shifted position until all the clones are done.
+            // If cloning panics, they are leaked rather than dropped twice.
+            self.set_len(0);
+            ptr::copy(buf_ptr, buf_ptr.add(src_len), len);
+
+            for (i, item) in src.iter().enumerate() {
+                ptr::write(buf_ptr.add(i), item.clone());
+            }
+
+            self.set_len(len + src_len);
+        }
+    }
+
     /// Removes and returns the last element from the vector.
     ///
     /// If the vector is empty, return [`None`].
@@ -355,15 +796,21 @@ pub trait CommonVec<T> {
     where
         V: CommonVec<T>,
     {
-        let len = self.len();
-        let capacity = self.capacity();
+        self.try_append(other).unwrap();
+    }
 
+    /// Tries to move all the elements of `other` into `self`.
+    ///
+    /// `other` will become empty after this. If the capacity cannot be reserved,
+    /// returns an error and both vectors are left unchanged.
+    fn try_append<V>(&mut self, other: &mut V) -> Result<(), TryReserveError>
+    where
+        V: CommonVec<T>,
+    {
+        let len = self.len();
         let other_len = other.len();
-        let total_len = len + other_len;
 
-        if total_len > capacity {
-            self.reserve(total_len - capacity);
-        }
+        self.try_reserve(other_len)?;
 
         let buf_ptr = self.as_mut_ptr();
         let other_buf_ptr = other.as_ptr();
@@ -371,12 +818,71 @@ pub trait CommonVec<T> {
         unsafe {
             ptr::copy(other_buf_ptr, buf_ptr.add(len), other_len);
 
-            self.set_len(total_len);
+            self.set_len(len + other_len);
             other.set_len(0);
         }
-    }
 
-    // Not implemented: drain
+        return Ok(());
+    }
+
+    /// Splits the vector into two at position `at`.
+    ///
+    /// Returns a new vector that contains the elements in `[at, len)`
+    /// and the vector itself is left with the elements in `[0, at)`.
+    fn split_off(&mut self, at: usize) -> Self
+    where
+        Self: Sized + Default,
+    {
+        let len = self.len();
+
+        if at > len {
+            panic!("Index is out-of-range.");
+        }
+
+        let num_move = len - at;
+
+        let mut other = Self::default();
+        other.reserve(num_move);
+
+        unsafe {
+            ptr::copy_nonoverlapping(self.as_ptr().add(at), other.as_mut_ptr(), num_move);
+
+            self.set_len(at);
+            other.set_len(num_move);
+        }
+
+        return other;
+    }
+
+    /// Removes the elements in `range` from the vector and returns them as an iterator.
+    ///
+    /// The elements after `range` are shifted to close the gap when the iterator is dropped,
+    /// and the elements that haven't been yielded are dropped with it.
+    /// If the iterator is leaked, the vector might lose the elements after `range`.
+    fn drain<R>(&mut self, range: R) -> Drain<'_, T, Self>
+    where
+        R: ops::RangeBounds<usize>,
+    {
+        let len = self.len();
+
+        let start = match range.start_bound() {
+            ops::Bound::Included(&i) => i,
+            ops::Bound::Excluded(&i) => i.checked_add(1).expect("Index is out-of-range."),
+            ops::Bound::Unbounded => 0,
+        };
+
+        let end = match range.end_bound() {
+            ops::Bound::Included(&i) => i.checked_add(1).expect("Index is out-of-range."),
+            ops::Bound::Excluded(&i) => i,
+            ops::Bound::Unbounded => len,
+        };
+
+        if start > end || end > len {
+            panic!("Index is out-of-range.");
+        }
+
+        return Drain::new(self, start, end);
+    }
 
     /// Moves all elements in the vector.
     fn clear(&mut self) {
@@ -389,6 +895,11 @@ pub trait CommonVec<T> {
         }
     }
 
+    /// Removes all elements in the vector, dropping them from the last to the first.
+    fn clear_lifo(&mut self) {
+        self.truncate_lifo(0);
+    }
+
     /// Returns the number of elements in the vector.
     fn len(&self) -> usize;
 
@@ -409,7 +920,9 @@ pub trait CommonVec<T> {
 
         self.truncate(new_len);
 
-        if len > new_len {
+        if new_len > len {
+            self.reserve(new_len - len);
+
             let buf_ptr = self.as_mut_ptr();
 
             for i in len..new_len {
@@ -436,12 +949,120 @@ pub trait CommonVec<T> {
             slice::from_raw_parts_mut(buf_ptr.add(len) as *mut mem::MaybeUninit<T>, capacity - len)
         };
     }
+
+    /// Returns an iterator over the unused space of the buffer.
+    fn spare_iter_mut(&mut self) -> slice::IterMut<'_, mem::MaybeUninit<T>> {
+        return self.spare_capacity_mut().iter_mut();
+    }
 }
 
 // TryReserveError ---------------------------------------------------------------------------------
 
+/// The error when the capacity of a vector cannot be reserved.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TryReserveError {
+    kind: TryReserveErrorKind,
+}
+
+/// The reason why the capacity of a vector cannot be reserved.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TryReserveErrorKind {
+    /// The required capacity exceeds the maximum capacity of the vector,
+    /// e.g. the fixed capacity of [`StaticVec`] or `usize::MAX`.
+    CapacityOverflow,
+
+    /// The memory allocator failed to allocate the required capacity.
+    AllocError,
+}
+
+impl TryReserveError {
+    /// Returns the reason why the capacity cannot be reserved.
+    pub fn kind(&self) -> TryReserveErrorKind {
+        return self.kind;
+    }
+}
+
+impl From<TryReserveErrorKind> for TryReserveError {
+    fn from(kind: TryReserveErrorKind) -> Self {
+        return Self { kind };
+    }
+}
+
+impl error::Error for TryReserveError {}
+
+impl fmt::Display for TryReserveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("memory allocation failed")?;
+
+        return f.write_str(match self.kind {
+            TryReserveErrorKind::CapacityOverflow => {
+                " because the computed capacity exceeded the collection's maximum"
+            }
+            TryReserveErrorKind::AllocError => " because the memory allocator returned an error",
+        });
+    }
+}
+
+// DupPolicy ---------------------------------------------------------------------------------------
+
+/// What to do when an element being inserted to a sorted vector is equal to an existing one.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DupPolicy {
+    /// Inserts the element next to an existing equal element.
+    Allow,
+
+    /// Doesn't insert the element.
+    Reject,
+
+    /// Replaces the existing equal element by the new one.
+    Replace,
+}
+
+// FrameError --------------------------------------------------------------------------------------
+
+/// The error when a length-prefixed frame or a sequence of encoded integers cannot be read.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum FrameError {
+    /// The input is shorter than the length prefix or the length it specifies,
+    /// or it ends in the middle of an encoded integer.
+    Truncated,
+
+    /// The number of elements in the input exceeds the capacity of the vector.
+    CapacityExceeded,
+}
+
+// FromBytesError ----------------------------------------------------------------------------------
+
+/// The error when a vector cannot be constructed from a nul-terminated byte buffer.
 #[derive(Clone, PartialEq, Eq, Debug)]
-pub struct TryReserveError;
+pub enum FromBytesError {
+    /// There is no nul terminator in the buffer.
+    NotNulTerminated,
+
+    /// The content before the nul terminator exceeds the capacity of the vector.
+    CapacityExceeded,
+}
+
+// ContentHasher -----------------------------------------------------------------------------------
+
+/// A hasher using the FxHash algorithm without any random seed,
+/// so the same data always produces the same hash.
+struct ContentHasher {
+    hash: u64,
+}
+
+impl hash::Hasher for ContentHasher {
+    fn finish(&self) -> u64 {
+        return self.hash;
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.hash =
+                (self.hash.rotate_left(5) ^ *byte as u64).wrapping_mul(0x517C_C1B7_2722_0A95);
+        }
+    }
+}
 
 // =================================================================================================
 // Statically allocated vector
@@ -470,6 +1091,61 @@ impl<T, const C: usize> StaticVec<T, C> {
     pub fn with_capacity(_capacity: usize) -> Self {
         return Self::new();
     }
+
+    /// Constructs a new `StaticVec<T, C>` that contains the elements of `arr`.
+    ///
+    /// Panics if the length of the array exceeds the capacity of the vector.
+    pub fn from_array<const N: usize>(arr: [T; N]) -> Self {
+        assert!(N <= C, "Array length exceeds the capacity.");
+
+        let arr = mem::ManuallyDrop::new(arr);
+        let mut vec = Self::new();
+
+        // The elements are moved to the vector, hence the array must not be dropped.
+        unsafe {
+            ptr::copy_nonoverlapping(arr.as_ptr(), vec.as_mut_ptr(), N);
+            vec.set_len(N);
+        }
+
+        return vec;
+    }
+
+    /// Constructs a new `StaticVec<T, C>` that is full of the elements of `arr`.
+    ///
+    /// Unlike [`from_array`], the length of the array must be equal to the capacity,
+    /// which allows this function to be used in constant context.
+    pub const fn from_exact_array(arr: [T; C]) -> Self {
+        return Self { len: C, buffer: mem::MaybeUninit::new(arr) };
+    }
+
+    /// Constructs a new `StaticVec<T, C>` that is full of the elements returned by `f`,
+    /// which is called with the index of each element in order.
+    pub fn from_fn<F>(mut f: F) -> Self
+    where
+        F: FnMut(usize) -> T,
+    {
+        let mut vec = Self::new();
+
+        for i in 0..C {
+            vec.push(f(i));
+        }
+
+        return vec;
+    }
+}
+
+impl<T, const C: usize> Default for StaticVec<T, C> {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl<T, const C: usize> Drop for StaticVec<T, C> {
+    fn drop(&mut self) {
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.as_mut_ptr(), self.len));
+        }
+    }
 }
 
 // Common vector methods ---------------------------------------------------------------------------
@@ -480,10 +1156,9 @@ impl<T, const C: usize> CommonVec<T> for StaticVec<T, C> {
     }
 
     fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
-        if self.len + additional <= C {
-            return Ok(());
-        } else {
-            return Err(TryReserveError);
+        match self.len.checked_add(additional) {
+            Some(required) if required <= C => return Ok(()),
+            _ => return Err(TryReserveErrorKind::CapacityOverflow.into()),
         }
     }
 
@@ -504,3 +1179,2592 @@ impl<T, const C: usize> CommonVec<T> for StaticVec<T, C> {
         return self.len;
     }
 }
+
+// Standard traits ---------------------------------------------------------------------------------
+
+impl<T, const C: usize> ops::Deref for StaticVec<T, C> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        return self.as_slice();
+    }
+}
+
+impl<T, const C: usize> ops::DerefMut for StaticVec<T, C> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        return self.as_mut_slice();
+    }
+}
+
+impl<T: Clone, const C: usize> Clone for StaticVec<T, C> {
+    fn clone(&self) -> Self {
+        // If cloning an element panics, the elements that have been cloned
+        // are dropped together with the new vector.
+        let mut other = Self::new();
+
+        for item in self.as_slice() {
+            other.push(item.clone());
+        }
+
+        return other;
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        let common_len = self.len().min(source.len());
+        self.truncate(common_len);
+
+        // The existing elements are reused so that they can keep their resources.
+        self.as_mut_slice().clone_from_slice(&source.as_slice()[..common_len]);
+
+        for item in &source.as_slice()[common_len..] {
+            self.push(item.clone());
+        }
+    }
+}
+
+impl<T: fmt::Debug, const C: usize> fmt::Debug for StaticVec<T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return self.as_slice().fmt(f);
+    }
+}
+
+impl<T, U, const C: usize, const D: usize> PartialEq<StaticVec<U, D>> for StaticVec<T, C>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &StaticVec<U, D>) -> bool {
+        return self.as_slice() == other.as_slice();
+    }
+}
+
+impl<T, U, const C: usize> PartialEq<[U]> for StaticVec<T, C>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &[U]) -> bool {
+        return self.as_slice() == other;
+    }
+}
+
+impl<T, U, const C: usize> PartialEq<&[U]> for StaticVec<T, C>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &&[U]) -> bool {
+        return self.as_slice() == *other;
+    }
+}
+
+impl<T, U, const C: usize, const N: usize> PartialEq<[U; N]> for StaticVec<T, C>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &[U; N]) -> bool {
+        return self.as_slice() == other;
+    }
+}
+
+impl<T: Eq, const C: usize> Eq for StaticVec<T, C> {}
+
+impl<T: PartialOrd, const C: usize, const D: usize> PartialOrd<StaticVec<T, D>>
+    for StaticVec<T, C>
+{
+    fn partial_cmp(&self, other: &StaticVec<T, D>) -> Option<cmp::Ordering> {
+        return self.as_slice().partial_cmp(other.as_slice());
+    }
+}
+
+impl<T: Ord, const C: usize> Ord for StaticVec<T, C> {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        return self.as_slice().cmp(other.as_slice());
+    }
+}
+
+impl<T: hash::Hash, const C: usize> hash::Hash for StaticVec<T, C> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
+
+impl<T, const C: usize> borrow::Borrow<[T]> for StaticVec<T, C> {
+    fn borrow(&self) -> &[T] {
+        return self.as_slice();
+    }
+}
+
+impl<T, const C: usize> borrow::BorrowMut<[T]> for StaticVec<T, C> {
+    fn borrow_mut(&mut self) -> &mut [T] {
+        return self.as_mut_slice();
+    }
+}
+
+impl<T, const C: usize> Extend<T> for StaticVec<T, C> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.extend_from_iter(iter);
+    }
+}
+
+impl<'a, T, const C: usize> IntoIterator for &'a StaticVec<T, C> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        return self.iter();
+    }
+}
+
+impl<'a, T, const C: usize> IntoIterator for &'a mut StaticVec<T, C> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        return self.iter_mut();
+    }
+}
+
+// Static vector methods ---------------------------------------------------------------------------
+
+impl<T, const C: usize> StaticVec<T, C> {
+    /// Panics if the internal state of the vector is inconsistent.
+    ///
+    /// This is a debugging aid for fuzzing and property tests to catch misuse of [`set_len`]
+    /// as early as possible. It's only available if debug assertions are enabled.
+    #[cfg(any(test, debug_assertions))]
+    pub fn assert_invariants(&self) {
+        assert!(self.len <= C, "Length exceeds the capacity.");
+    }
+
+    /// Panics like [`assert_invariants`] or if any live element is equal to `sentinel`.
+    ///
+    /// The spare capacity is then filled with `sentinel`, so that the next check catches
+    /// the length being extended over the slots that haven't been written.
+    #[cfg(test)]
+    pub(crate) fn assert_invariants_with_sentinel(&mut self, sentinel: T)
+    where
+        T: Copy + PartialEq,
+    {
+        self.assert_invariants();
+        assert!(!self.as_slice().contains(&sentinel), "Live element is not initialized.");
+
+        let buf_ptr = self.as_mut_ptr();
+
+        for i in self.len..C {
+            unsafe {
+                ptr::write(buf_ptr.add(i), sentinel);
+            }
+        }
+    }
+
+    /// Constructs a new vector that contains clones of `pattern` repeated `times` times.
+    ///
+    /// If the result doesn't fit in the capacity of the vector, returns an error.
+    pub fn repeat_pattern(pattern: &[T], times: usize) -> Result<Self, TryReserveError>
+    where
+        T: Clone,
+    {
+        let mut vec = Self::new();
+        vec.try_reserve(
+            pattern.len().checked_mul(times).ok_or(TryReserveErrorKind::CapacityOverflow)?,
+        )?;
+
+        for _ in 0..times {
+            for item in pattern {
+                vec.push(item.clone());
+            }
+        }
+
+        return Ok(vec);
+    }
+
+    /// Moves all the elements to a new vector with the capacity of `D` elements.
+    ///
+    /// The capacity can be either smaller or larger than the current one as long as
+    /// all the elements fit. Otherwise, returns the original vector as the error.
+    pub fn try_recapacity<const D: usize>(mut self) -> Result<StaticVec<T, D>, StaticVec<T, C>> {
+        let len = self.len();
+
+        if len > D {
+            return Err(self);
+        }
+
+        let mut other = StaticVec::<T, D>::new();
+
+        unsafe {
+            ptr::copy_nonoverlapping(self.as_ptr(), other.as_mut_ptr(), len);
+            self.set_len(0);
+            other.set_len(len);
+        }
+
+        return Ok(other);
+    }
+
+    /// Appends clones of as many elements of `src` as the remaining capacity allows
+    /// and returns the number of appended elements.
+    ///
+    /// The rest of `src` is ignored.
+    pub fn extend_saturating(&mut self, src: &[T]) -> usize
+    where
+        T: Clone,
+    {
+        let num_append = src.len().min(C - self.len());
+
+        for item in &src[..num_append] {
+            self.push(item.clone());
+        }
+
+        return num_append;
+    }
+
+    /// Shortens the vector to the first `len` elements and returns the rest as a new vector.
+    ///
+    /// Unlike [`truncate`], the removed elements are moved to the returned vector
+    /// rather than dropped. If the current number of elements is less than `len`,
+    /// returns an empty vector.
+    pub fn truncate_take(&mut self, len: usize) -> StaticVec<T, C> {
+        let mut tail = StaticVec::<T, C>::new();
+        let cur_len = self.len();
+
+        if cur_len > len {
+            let num_take = cur_len - len;
+
+            unsafe {
+                ptr::copy_nonoverlapping(self.as_ptr().add(len), tail.as_mut_ptr(), num_take);
+                self.set_len(len);
+                tail.set_len(num_take);
+            }
+        }
+
+        return tail;
+    }
+
+    /// Consumes the vector and moves each element into either of two new vectors.
+    ///
+    /// The first vector contains the elements `e` for which `f(&e)` returns `true`
+    /// and the second vector contains the rest.
+    pub fn partition<F>(mut self, mut f: F) -> (StaticVec<T, C>, StaticVec<T, C>)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let len = self.len();
+        let buf_ptr = self.as_ptr();
+
+        let mut matched = StaticVec::<T, C>::new();
+        let mut unmatched = StaticVec::<T, C>::new();
+
+        unsafe {
+            // The ownership of the elements is transferred to the output vectors.
+            self.set_len(0);
+
+            for i in 0..len {
+                let item = ptr::read(buf_ptr.add(i));
+
+                if f(&item) {
+                    matched.push(item);
+                } else {
+                    unmatched.push(item);
+                }
+            }
+        }
+
+        return (matched, unmatched);
+    }
+
+    /// Appends a frame that contains the vector to `out`.
+    ///
+    /// The frame consists of the number of elements as a 4-byte big-endian length prefix
+    /// followed by the elements as bytes. It can be read by [`read_length_prefixed`].
+    ///
+    /// If `out` doesn't have enough capacity for the whole frame, returns an error
+    /// and `out` is unchanged.
+    pub fn write_length_prefixed<const D: usize>(
+        &self,
+        out: &mut StaticVec<u8, D>,
+    ) -> Result<(), TryReserveError>
+    where
+        T: Into<u8> + Copy,
+    {
+        let len = u32::try_from(self.len()).map_err(|_| TryReserveErrorKind::CapacityOverflow)?;
+        out.try_reserve(4 + self.len())?;
+
+        for byte in len.to_be_bytes() {
+            out.push(byte);
+        }
+
+        for item in self.as_slice() {
+            out.push((*item).into());
+        }
+
+        return Ok(());
+    }
+
+    /// Replaces the content of `out` by clones of the elements of the vector
+    /// rotated to the left by `mid` positions, i.e. `self[mid..]` followed by `self[..mid]`.
+    ///
+    /// Unlike [`slice::rotate_left`], the vector itself is unchanged.
+    /// Panics if `mid` is greater than the length of the vector.
+    pub fn copy_rotated_into(&self, mid: usize, out: &mut StaticVec<T, C>)
+    where
+        T: Clone,
+    {
+        if mid > self.len() {
+            panic!("Index is out-of-range.");
+        }
+
+        let (head, tail) = self.as_slice().split_at(mid);
+
+        out.clear();
+
+        for item in tail.iter().chain(head) {
+            out.push(item.clone());
+        }
+    }
+
+    /// Sorts the vector in place without preserving the order of equal elements.
+    ///
+    /// Unlike a stable sort, no scratch space needs to be allocated.
+    pub fn sort_unstable(&mut self)
+    where
+        T: Ord,
+    {
+        self.as_mut_slice().sort_unstable();
+    }
+
+    /// Sorts the vector in place with the comparator function `f`
+    /// without preserving the order of equal elements.
+    pub fn sort_unstable_by<F>(&mut self, f: F)
+    where
+        F: FnMut(&T, &T) -> cmp::Ordering,
+    {
+        self.as_mut_slice().sort_unstable_by(f);
+    }
+
+    /// Sorts the vector in place by the keys extracted by `f`
+    /// without preserving the order of equal elements.
+    pub fn sort_unstable_by_key<K, F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.as_mut_slice().sort_unstable_by_key(f);
+    }
+
+    /// Sorts the vector and removes all duplicates, leaving each distinct element once.
+    ///
+    /// The sort is unstable and doesn't allocate.
+    pub fn sort_dedup(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_unstable();
+        self.dedup();
+    }
+
+    /// Appends the elements of the vector to `out` except consecutive repeated elements.
+    ///
+    /// Unlike [`dedup_by`], the vector itself is unchanged.
+    ///
+    /// If `out` doesn't have enough capacity, returns an error
+    /// and `out` only contains the elements that fit.
+    pub fn dedup_into<const D: usize>(
+        &self,
+        out: &mut StaticVec<T, D>,
+    ) -> Result<(), TryReserveError>
+    where
+        T: PartialEq + Clone,
+    {
+        let mut prev: Option<&T> = None;
+
+        for item in self.as_slice() {
+            if prev != Some(item) {
+                out.try_reserve(1)?;
+                out.push(item.clone());
+
+                prev = Some(item);
+            }
+        }
+
+        return Ok(());
+    }
+
+    /// Appends the elements that exist in both the vector and `other` to `out`.
+    ///
+    /// Both the vector and `other` must be sorted. Otherwise, the result is unspecified.
+    ///
+    /// If `out` doesn't have enough capacity, returns an error
+    /// and `out` only contains the elements that fit.
+    pub fn intersect_sorted<const D: usize, const E: usize>(
+        &self,
+        other: &StaticVec<T, D>,
+        out: &mut StaticVec<T, E>,
+    ) -> Result<(), TryReserveError>
+    where
+        T: Ord + Clone,
+    {
+        let a = self.as_slice();
+        let b = other.as_slice();
+
+        let mut i = 0usize;
+        let mut j = 0usize;
+
+        while i < a.len() && j < b.len() {
+            match a[i].cmp(&b[j]) {
+                cmp::Ordering::Less => i += 1,
+                cmp::Ordering::Greater => j += 1,
+                cmp::Ordering::Equal => {
+                    out.try_reserve(1)?;
+                    out.push(a[i].clone());
+
+                    i += 1;
+                    j += 1;
+                }
+            }
+        }
+
+        return Ok(());
+    }
+
+    /// Computes a hash of all the elements in the vector.
+    ///
+    /// Unlike the hashers of `std`, the hash isn't randomly seeded, so the same contents
+    /// always produce the same hash on the same target. It's suitable for change detection
+    /// but not for hash tables exposed to untrusted input.
+    pub fn content_hash(&self) -> u64
+    where
+        T: hash::Hash,
+    {
+        let mut hasher = ContentHasher { hash: 0 };
+        hash::Hash::hash(self.as_slice(), &mut hasher);
+
+        return hash::Hasher::finish(&hasher);
+    }
+
+    /// Appends clones of the elements of `a` and `b` to `out` alternately, starting with `a`.
+    ///
+    /// When one of the vectors runs out of elements, the rest of the other one is appended.
+    ///
+    /// If `out` doesn't have enough capacity, returns an error
+    /// and `out` only contains the elements that fit.
+    pub fn interleave<const D: usize, const E: usize>(
+        a: &StaticVec<T, C>,
+        b: &StaticVec<T, D>,
+        out: &mut StaticVec<T, E>,
+    ) -> Result<(), TryReserveError>
+    where
+        T: Clone,
+    {
+        let a = a.as_slice();
+        let b = b.as_slice();
+
+        for i in 0..a.len().max(b.len()) {
+            for item in [a.get(i), b.get(i)].into_iter().flatten() {
+                out.try_reserve(1)?;
+                out.push(item.clone());
+            }
+        }
+
+        return Ok(());
+    }
+
+    /// Reverses the order of the elements within `range` in place,
+    /// leaving the elements outside of it untouched.
+    ///
+    /// Panics if the range is out of the vector.
+    pub fn reverse_range(&mut self, range: ops::Range<usize>) {
+        if range.start > range.end || range.end > self.len {
+            panic!("Index is out-of-range.");
+        }
+
+        self.as_mut_slice()[range].reverse();
+    }
+
+    /// Appends the running totals of the elements to `out`, i.e. the `i`-th appended element
+    /// is the sum of the first `i + 1` elements of the vector.
+    ///
+    /// If `out` doesn't have enough capacity, returns an error
+    /// and `out` only contains the totals that fit.
+    pub fn prefix_sum<const D: usize>(
+        &self,
+        out: &mut StaticVec<T, D>,
+    ) -> Result<(), TryReserveError>
+    where
+        T: Clone + ops::Add<Output = T>,
+    {
+        let mut total: Option<T> = None;
+
+        for item in self.as_slice() {
+            out.try_reserve(1)?;
+
+            let sum = match total {
+                None => item.clone(),
+                Some(total) => total + item.clone(),
+            };
+
+            out.push(sum.clone());
+            total = Some(sum);
+        }
+
+        return Ok(());
+    }
+
+    /// Returns an iterator over the sub-slices of the vector separated by elements equal to
+    /// `delim`, which are not contained in the sub-slices.
+    ///
+    /// Similar to `split` of slices, an empty sub-slice is returned for a delimiter
+    /// at the beginning or the end of the vector and between adjacent delimiters.
+    pub fn split_by<'a>(&'a self, delim: &'a T) -> impl Iterator<Item = &'a [T]> + 'a
+    where
+        T: PartialEq,
+    {
+        return self.as_slice().split(move |item| item == delim);
+    }
+
+    /// Slides a window of `W` elements over the vector and pushes the reduction of each window
+    /// computed by `f` to `out`, e.g. the moving sum.
+    ///
+    /// Nothing is pushed if the vector is shorter than the window.
+    /// If `out` doesn't have enough capacity, returns an error
+    /// and `out` only contains the reductions that fit.
+    ///
+    /// Panics if `W` is zero.
+    pub fn windowed_reduce<const W: usize, U, F>(
+        &self,
+        mut f: F,
+        out: &mut StaticVec<U, C>,
+    ) -> Result<(), TryReserveError>
+    where
+        F: FnMut(&[T]) -> U,
+    {
+        assert!(W != 0, "Window size is zero.");
+
+        for window in self.as_slice().windows(W) {
+            out.try_reserve(1)?;
+            out.push(f(window));
+        }
+
+        return Ok(());
+    }
+
+    /// Empties the vector so that its buffer can be reused.
+    ///
+    /// The elements are moved out to `f` from the first to the last, e.g. to release
+    /// the resources they hold. If `zero` is `true`, the whole backing buffer is then
+    /// overwritten with zero bytes so that the previous contents cannot leak
+    /// through the spare capacity.
+    pub fn reset_with<F>(&mut self, zero: bool, f: F)
+    where
+        F: FnMut(T),
+    {
+        self.drain(..).for_each(f);
+
+        if zero {
+            let buf_ptr = self.buffer.as_mut_ptr() as *mut u8;
+
+            // Volatile writes are used so that the compiler doesn't optimize the zeroing away
+            // as the buffer is never read afterwards.
+            for i in 0..mem::size_of::<[T; C]>() {
+                unsafe { ptr::write_volatile(buf_ptr.add(i), 0) };
+            }
+        }
+    }
+
+    /// Appends an element to the back of the vector, keeping it as a sliding window.
+    ///
+    /// If the vector is full, the oldest (front) element is removed and returned
+    /// before `value` is appended. Otherwise, returns `None`. For a zero-capacity vector,
+    /// `value` itself is returned.
+    pub fn push_window(&mut self, value: T) -> Option<T> {
+        if C == 0 {
+            return Some(value);
+        }
+
+        let evicted = if self.len() == C { Some(self.remove(0)) } else { None };
+        self.push(value);
+
+        return evicted;
+    }
+}
+
+// Byte vector methods -----------------------------------------------------------------------------
+
+impl<const C: usize> StaticVec<u8, C> {
+    /// Constructs a new `StaticVec<u8, C>` that contains a copy of `bytes`.
+    ///
+    /// This function can be used in constant context, e.g. to construct a vector
+    /// from a byte string literal. Panics if `bytes` is longer than the capacity.
+    pub const fn from_bytes(bytes: &[u8]) -> Self {
+        assert!(bytes.len() <= C, "Capacity is exceeded.");
+
+        let mut buffer = [0u8; C];
+        let mut i = 0usize;
+
+        while i < bytes.len() {
+            buffer[i] = bytes[i];
+            i += 1;
+        }
+
+        return Self { len: bytes.len(), buffer: mem::MaybeUninit::new(buffer) };
+    }
+
+    /// Empties the vector and zeroes its whole buffer so that it can be safely reused.
+    ///
+    /// See [`reset_with`] for keeping the buffer contents.
+    pub fn reset(&mut self) {
+        self.reset_with(true, drop);
+    }
+
+    /// Reads a frame written by [`write_length_prefixed`] from the beginning of `buf`.
+    ///
+    /// Returns the total number of bytes of the frame, including the length prefix,
+    /// and the vector that contains the frame data.
+    pub fn read_length_prefixed(buf: &[u8]) -> Result<(usize, Self), FrameError> {
+        if buf.len() < 4 {
+            return Err(FrameError::Truncated);
+        }
+
+        let len = u32::from_be_bytes([buf[0], buf[1], buf[2], buf[3]]) as usize;
+        let data = &buf[4..];
+
+        if len > data.len() {
+            return Err(FrameError::Truncated);
+        }
+
+        if len > C {
+            return Err(FrameError::CapacityExceeded);
+        }
+
+        return Ok((4 + len, Self::from_bytes(&data[..len])));
+    }
+
+    /// Constructs a new `StaticVec<u8, C>` that contains a copy of the bytes
+    /// before the first nul terminator in `bytes`, e.g. a C string.
+    ///
+    /// The nul terminator itself is not copied.
+    pub fn from_cstr_bytes(bytes: &[u8]) -> Result<Self, FromBytesError> {
+        let len = bytes.iter().position(|b| *b == 0).ok_or(FromBytesError::NotNulTerminated)?;
+
+        if len > C {
+            return Err(FromBytesError::CapacityExceeded);
+        }
+
+        return Ok(Self::from_bytes(&bytes[..len]));
+    }
+
+    /// Returns a string slice of the entire vector if it contains valid UTF-8.
+    pub fn as_str(&self) -> Result<&str, str::Utf8Error> {
+        return str::from_utf8(self.as_slice());
+    }
+
+    /// Returns a string slice of the entire vector without checking that it contains valid UTF-8.
+    ///
+    /// # Safety
+    ///
+    /// The content of the vector must be valid UTF-8.
+    pub unsafe fn as_str_unchecked(&self) -> &str {
+        return str::from_utf8_unchecked(self.as_slice());
+    }
+
+    /// Reinterprets the entire vector as a slice of `U`, returning the unaligned prefix
+    /// and suffix bytes as well.
+    ///
+    /// The middle slice is as long as possible under the alignment of `U`,
+    /// but the prefix and suffix may be longer than necessary.
+    /// See [`slice::align_to`] for details.
+    ///
+    /// # Safety
+    ///
+    /// Every bit pattern of `size_of::<U>()` bytes read from the vector
+    /// must be a valid value of `U`, e.g. `U` is a primitive integer type.
+    pub unsafe fn align_to<U>(&self) -> (&[u8], &[U], &[u8]) {
+        return self.as_slice().align_to::<U>();
+    }
+
+    /// Computes the CRC-32 checksum of the entire vector.
+    ///
+    /// The algorithm is the widely used CRC-32/ISO-HDLC (as in Ethernet, zlib and PNG):
+    /// reflected polynomial `0xEDB88320`, initial value and final XOR of `0xFFFFFFFF`.
+    /// It is computed bit by bit without a lookup table to keep the code size small.
+    pub fn checksum_u32(&self) -> u32 {
+        let mut crc = 0xFFFF_FFFFu32;
+
+        for byte in self.as_slice() {
+            crc ^= *byte as u32;
+
+            for _ in 0..8 {
+                let mask = (crc & 1).wrapping_neg();
+                crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+            }
+        }
+
+        return !crc;
+    }
+
+    /// Sets the bit at position `index`, treating the vector as a bitset.
+    ///
+    /// Bit `index` is bit `index % 8` of byte `index / 8`. If the vector is too short,
+    /// it's extended with zero bytes to cover `index`.
+    pub fn set_bit(&mut self, index: usize) {
+        let byte_index = index / 8;
+
+        if byte_index >= self.len() {
+            self.resize_with(byte_index + 1, || 0);
+        }
+
+        self.as_mut_slice()[byte_index] |= 1 << (index % 8);
+    }
+
+    /// Clears the bit at position `index`, treating the vector as a bitset.
+    ///
+    /// The bits beyond the end of the vector are already considered clear,
+    /// hence the vector is never extended.
+    pub fn clear_bit(&mut self, index: usize) {
+        let byte_index = index / 8;
+
+        if byte_index < self.len() {
+            self.as_mut_slice()[byte_index] &= !(1 << (index % 8));
+        }
+    }
+
+    /// Returns whether the bit at position `index` is set, treating the vector as a bitset.
+    ///
+    /// The bits beyond the end of the vector are clear.
+    pub fn get_bit(&self, index: usize) -> bool {
+        let byte_index = index / 8;
+
+        return byte_index < self.len() && (self.as_slice()[byte_index] >> (index % 8)) & 1 != 0;
+    }
+}
+
+impl<const C: usize> fmt::Write for StaticVec<u8, C> {
+    /// Appends the UTF-8 bytes of `s` to the vector.
+    ///
+    /// If the vector doesn't have enough capacity for all of them, returns an error
+    /// and the vector is left unchanged.
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.try_reserve(s.len()).map_err(|_| fmt::Error)?;
+        self.extend(s.bytes());
+
+        return Ok(());
+    }
+}
+
+// Integer vector methods --------------------------------------------------------------------------
+
+macro_rules! impl_int_vec {
+    ($($t:ty),*) => {$(
+        impl<const C: usize> StaticVec<$t, C> {
+            /// Serializes the elements in order to a byte vector, each in little-endian byte order.
+            ///
+            /// If the byte vector doesn't have enough capacity, returns an error.
+            pub fn to_le_bytes<const B: usize>(&self) -> Result<StaticVec<u8, B>, TryReserveError> {
+                return self.encode(<$t>::to_le_bytes);
+            }
+
+            /// Serializes the elements in order to a byte vector, each in big-endian byte order.
+            ///
+            /// If the byte vector doesn't have enough capacity, returns an error.
+            pub fn to_be_bytes<const B: usize>(&self) -> Result<StaticVec<u8, B>, TryReserveError> {
+                return self.encode(<$t>::to_be_bytes);
+            }
+
+            /// Deserializes the elements written by [`to_le_bytes`].
+            ///
+            /// [`to_le_bytes`]: Self::to_le_bytes
+            pub fn from_le_bytes(bytes: &[u8]) -> Result<Self, FrameError> {
+                return Self::decode(bytes, <$t>::from_le_bytes);
+            }
+
+            /// Deserializes the elements written by [`to_be_bytes`].
+            ///
+            /// [`to_be_bytes`]: Self::to_be_bytes
+            pub fn from_be_bytes(bytes: &[u8]) -> Result<Self, FrameError> {
+                return Self::decode(bytes, <$t>::from_be_bytes);
+            }
+
+            fn encode<const B: usize>(
+                &self,
+                encode: fn($t) -> [u8; mem::size_of::<$t>()],
+            ) -> Result<StaticVec<u8, B>, TryReserveError> {
+                let mut out = StaticVec::<u8, B>::new();
+                out.try_reserve(self.len() * mem::size_of::<$t>())?;
+
+                for item in self.as_slice() {
+                    for byte in encode(*item) {
+                        out.push(byte);
+                    }
+                }
+
+                return Ok(out);
+            }
+
+            fn decode(
+                bytes: &[u8],
+                decode: fn([u8; mem::size_of::<$t>()]) -> $t,
+            ) -> Result<Self, FrameError> {
+                let size = mem::size_of::<$t>();
+
+                if bytes.len() % size != 0 {
+                    return Err(FrameError::Truncated);
+                }
+
+                if bytes.len() / size > C {
+                    return Err(FrameError::CapacityExceeded);
+                }
+
+                let mut vec = Self::new();
+
+                for chunk in bytes.chunks_exact(size) {
+                    vec.push(decode(chunk.try_into().unwrap()));
+                }
+
+                return Ok(vec);
+            }
+        }
+    )*};
+}
+
+impl_int_vec!(u16, u32, u64, u128, i16, i32, i64, i128);
+
+// =================================================================================================
+// Tests
+// =================================================================================================
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::panic::{self, AssertUnwindSafe};
+
+    use super::*;
+    use crate::vec::testing::{check_common_vec, values, DropCounter, Tracked};
+
+    // Common vector -------------------------------------------------------------------------------
+
+    #[test]
+    fn common_vec_conformance() {
+        check_common_vec(StaticVec::<_, 8>::new());
+    }
+
+    // Append --------------------------------------------------------------------------------------
+
+    #[test]
+    fn try_append_moves_all_elements() {
+        let counter = DropCounter::new();
+        let mut vec = StaticVec::<_, 6>::new();
+        let mut other = StaticVec::<_, 4>::new();
+
+        for value in 0..3 {
+            vec.push(counter.item(value));
+            other.push(counter.item(value + 3));
+        }
+
+        assert!(vec.try_append(&mut other).is_ok());
+        assert_eq!(values(&vec), [0, 1, 2, 3, 4, 5]);
+        assert!(other.is_empty());
+        assert_eq!(counter.count(), 0);
+
+        drop((vec, other));
+        assert_eq!(counter.count(), 6);
+    }
+
+    #[test]
+    fn try_append_into_full_vector() {
+        let counter = DropCounter::new();
+        let mut vec = StaticVec::<_, 4>::new();
+        let mut other = StaticVec::<_, 4>::new();
+
+        for value in 0..3 {
+            vec.push(counter.item(value));
+            other.push(counter.item(value + 3));
+        }
+
+        let error = vec.try_append(&mut other).unwrap_err();
+        assert_eq!(error.kind(), TryReserveErrorKind::CapacityOverflow);
+
+        // Neither vector is modified.
+        assert_eq!(values(&vec), [0, 1, 2]);
+        assert_eq!(values(&other), [3, 4, 5]);
+        assert_eq!(counter.count(), 0);
+
+        drop((vec, other));
+        assert_eq!(counter.count(), 6);
+    }
+
+    #[test]
+    fn append_full_vector() {
+        let mut vec = StaticVec::<u8, 8>::from_array([1, 2]);
+        let mut other = StaticVec::<u8, 4>::from_array([3, 4, 5, 6]);
+
+        vec.append(&mut other);
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4, 5, 6]);
+        assert!(other.is_empty());
+
+        let mut other = StaticVec::<u8, 4>::from_array([7, 8, 9, 10]);
+
+        let error = vec.try_append(&mut other).unwrap_err();
+        assert_eq!(error.kind(), TryReserveErrorKind::CapacityOverflow);
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4, 5, 6]);
+        assert_eq!(other.as_slice(), [7, 8, 9, 10]);
+    }
+
+    #[test]
+    #[should_panic(expected = "CapacityOverflow")]
+    fn append_into_full_vector() {
+        let mut vec = StaticVec::<u8, 4>::from_array([1, 2]);
+        let mut other = StaticVec::<u8, 4>::from_array([3, 4, 5]);
+
+        vec.append(&mut other);
+    }
+
+    // Invariants ----------------------------------------------------------------------------------
+
+    #[test]
+    fn random_operations_keep_invariants() {
+        let mut vec = StaticVec::<u32, 16>::new();
+        let mut model = std::vec::Vec::<u32>::new();
+
+        // A linear congruential generator is enough to drive the operations.
+        let mut seed = 0x2545_f491_u32;
+        let mut next = || {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            return seed >> 16;
+        };
+
+        for _ in 0..10_000 {
+            let value = next();
+            let index = next() as usize % (model.len() + 1);
+
+            match next() % 4 {
+                0 if model.len() < 16 => {
+                    vec.push(value);
+                    model.push(value);
+                }
+                1 => assert_eq!(vec.pop(), model.pop()),
+                2 if model.len() < 16 => {
+                    vec.insert(index, value);
+                    model.insert(index, value);
+                }
+                3 if index < model.len() => assert_eq!(vec.remove(index), model.remove(index)),
+                _ => {}
+            }
+
+            vec.assert_invariants_with_sentinel(u32::MAX);
+            assert_eq!(vec.as_slice(), model.as_slice());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Length exceeds the capacity.")]
+    fn invariants_catch_bad_length() {
+        // The vector is never dropped as its length is invalid.
+        let mut vec = mem::ManuallyDrop::new(StaticVec::<u8, 4>::new());
+        vec.len = 5;
+        vec.assert_invariants();
+    }
+
+    #[test]
+    #[should_panic(expected = "Live element is not initialized.")]
+    fn invariants_catch_uninitialized_element() {
+        let mut vec = StaticVec::<u32, 4>::from_array([1, 2]);
+        vec.assert_invariants_with_sentinel(u32::MAX);
+
+        // The length is extended over a slot that only contains the sentinel.
+        unsafe { vec.set_len(3) };
+        vec.assert_invariants_with_sentinel(u32::MAX);
+    }
+
+    // Drop ----------------------------------------------------------------------------------------
+
+    #[test]
+    fn drop_drops_live_elements_once() {
+        let counter = DropCounter::new();
+        let mut vec = StaticVec::<_, 8>::new();
+
+        for value in 0..6 {
+            vec.push(counter.item(value));
+        }
+
+        vec.truncate(4);
+        assert_eq!(counter.count(), 2);
+
+        let popped = vec.pop().unwrap();
+        assert_eq!(counter.count(), 2);
+        drop(popped);
+        assert_eq!(counter.count(), 3);
+
+        // The element hidden by `set_len` is moved out beforehand, so it's not dropped twice.
+        let hidden = unsafe { ptr::read(vec.as_ptr().add(2)) };
+        unsafe { vec.set_len(2) };
+        assert_eq!(values(&vec), [0, 1]);
+
+        drop(vec);
+        assert_eq!(counter.count(), 5);
+
+        drop(hidden);
+        assert_eq!(counter.count(), 6);
+    }
+
+    #[test]
+    fn drop_empty_and_full() {
+        let counter = DropCounter::new();
+
+        drop(StaticVec::<Tracked, 4>::new());
+        assert_eq!(counter.count(), 0);
+
+        drop(StaticVec::<_, 4>::from_fn(|i| counter.item(i as i32)));
+        assert_eq!(counter.count(), 4);
+    }
+
+    // Clone ---------------------------------------------------------------------------------------
+
+    fn clone_source(counter: &DropCounter, panic_at: usize) -> StaticVec<Tracked, 8> {
+        return StaticVec::from_fn(|i| match i == panic_at {
+            true => counter.panicking_item(i as i32),
+            false => counter.item(i as i32),
+        });
+    }
+
+    #[test]
+    fn clone_and_clone_from() {
+        let counter = DropCounter::new();
+        let source = clone_source(&counter, usize::MAX);
+
+        let clone = source.clone();
+        assert_eq!(values(&clone), values(&source));
+
+        // Shorter destination: the existing elements are reused and the rest are appended.
+        let mut shorter = StaticVec::<_, 8>::new();
+        shorter.push(counter.item(10));
+        shorter.clone_from(&source);
+        assert_eq!(values(&shorter), values(&source));
+        assert_eq!(counter.count(), 1);
+
+        // Longer destination: the extra elements are dropped.
+        let mut longer = clone_source(&counter, usize::MAX);
+        longer.truncate(3);
+        assert_eq!(counter.count(), 6);
+        longer.clone_from(&source);
+        assert_eq!(values(&longer), values(&source));
+
+        let mut truncated = source.clone();
+        truncated.clone_from(&StaticVec::new());
+        assert!(truncated.is_empty());
+        assert_eq!(counter.count(), 17);
+
+        drop((source, clone, shorter, longer));
+        assert_eq!(counter.count(), 49);
+    }
+
+    #[test]
+    fn clone_panic_drops_each_clone_once() {
+        let counter = DropCounter::new();
+        let source = clone_source(&counter, 3);
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| source.clone()));
+        assert!(result.is_err());
+
+        // The 3 elements cloned before the panic are dropped, the source is untouched.
+        assert_eq!(counter.count(), 3);
+        assert_eq!(values(&source), [0, 1, 2, 3, 4, 5, 6, 7]);
+
+        drop(source);
+        assert_eq!(counter.count(), 11);
+    }
+
+    #[test]
+    fn clone_from_panic_drops_each_element_once() {
+        let counter = DropCounter::new();
+        let source = clone_source(&counter, 5);
+
+        let mut vec = StaticVec::<_, 8>::new();
+        vec.push(counter.item(10));
+        vec.push(counter.item(11));
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| vec.clone_from(&source)));
+        assert!(result.is_err());
+
+        // The 2 reused elements were overwritten by clones (dropping the old values), and the
+        // destination keeps the clones appended before the panic.
+        assert_eq!(values(&vec), [0, 1, 2, 3, 4]);
+        assert_eq!(counter.count(), 2);
+
+        drop(vec);
+        assert_eq!(counter.count(), 7);
+
+        drop(source);
+        assert_eq!(counter.count(), 15);
+    }
+
+    // Retain --------------------------------------------------------------------------------------
+
+    #[test]
+    fn retain_mut_panic_drops_each_element_once() {
+        let counter = DropCounter::new();
+        let mut vec = StaticVec::<_, 8>::new();
+
+        for value in 1..=5 {
+            vec.push(counter.item(value));
+        }
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            vec.retain_mut(|item| {
+                if item.value == 3 {
+                    panic!("Predicate panics.");
+                }
+
+                return item.value % 2 == 1;
+            });
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(values(&vec), [1, 3, 4, 5]);
+        assert_eq!(counter.count(), 1);
+
+        drop(vec);
+        assert_eq!(counter.count(), 5);
+    }
+
+    // Retain count --------------------------------------------------------------------------------
+
+    #[test]
+    fn retain_count() {
+        let mut vec = StaticVec::<i32, 8>::from_array([1, 2, 3, 4, 5]);
+
+        assert_eq!(vec.retain_count(|x| x % 2 == 1), 2);
+        assert_eq!(vec.as_slice(), [1, 3, 5]);
+
+        assert_eq!(vec.retain_count(|_| true), 0);
+        assert_eq!(vec.retain_count(|_| false), 3);
+        assert!(vec.is_empty());
+    }
+
+    // String slice --------------------------------------------------------------------------------
+
+    #[test]
+    fn as_str() {
+        let mut vec = StaticVec::<u8, 8>::new();
+        assert_eq!(vec.as_str(), Ok(""));
+
+        vec.extend("hé".bytes());
+        assert_eq!(vec.as_str(), Ok("hé"));
+        assert_eq!(unsafe { vec.as_str_unchecked() }, "hé");
+
+        // A truncated multi-byte character is invalid.
+        vec.pop();
+        assert_eq!(vec.as_str().unwrap_err().valid_up_to(), 1);
+
+        vec.truncate(1);
+        vec.push(0xff);
+        assert!(vec.as_str().is_err());
+    }
+
+    // Dedup into ----------------------------------------------------------------------------------
+
+    #[test]
+    fn dedup_into() {
+        let vec = StaticVec::<i32, 8>::from_array([1, 1, 2, 3, 3, 1]);
+
+        let mut out = StaticVec::<i32, 4>::new();
+        assert_eq!(vec.dedup_into(&mut out), Ok(()));
+        assert_eq!(out.as_slice(), [1, 2, 3, 1]);
+        assert_eq!(vec.as_slice(), [1, 1, 2, 3, 3, 1]);
+
+        // Only the unique elements that fit are written.
+        let mut small = StaticVec::<i32, 2>::new();
+        let error = vec.dedup_into(&mut small).unwrap_err();
+        assert_eq!(error.kind(), TryReserveErrorKind::CapacityOverflow);
+        assert_eq!(small.as_slice(), [1, 2]);
+    }
+
+    #[test]
+    fn dedup_into_appends() {
+        let vec = StaticVec::<i32, 4>::from_array([2, 2, 3]);
+        let mut out = StaticVec::<i32, 4>::from_array([2]);
+
+        assert_eq!(vec.dedup_into(&mut out), Ok(()));
+        assert_eq!(out.as_slice(), [2, 2, 3]);
+    }
+
+    // From bytes ----------------------------------------------------------------------------------
+
+    #[test]
+    fn from_bytes() {
+        const HELLO: StaticVec<u8, 8> = StaticVec::from_bytes(b"hello");
+
+        assert_eq!(HELLO.as_slice(), b"hello");
+        assert_eq!(StaticVec::<u8, 5>::from_bytes(b"hello").as_slice(), b"hello");
+        assert!(StaticVec::<u8, 0>::from_bytes(b"").is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Capacity is exceeded.")]
+    fn from_bytes_too_long() {
+        StaticVec::<u8, 2>::from_bytes(b"abc");
+    }
+
+    // Truncate take -------------------------------------------------------------------------------
+
+    #[test]
+    fn truncate_take() {
+        let counter = DropCounter::new();
+        let mut vec = StaticVec::<_, 8>::from_fn(|i| counter.item(i as i32));
+
+        let tail = vec.truncate_take(5);
+        assert_eq!(values(&vec), [0, 1, 2, 3, 4]);
+        assert_eq!(values(&tail), [5, 6, 7]);
+        assert_eq!(counter.count(), 0);
+
+        assert!(vec.truncate_take(5).is_empty());
+        assert!(vec.truncate_take(10).is_empty());
+        assert_eq!(vec.truncate_take(0).len(), 5);
+        assert!(vec.is_empty());
+        assert_eq!(counter.count(), 5);
+
+        drop(tail);
+        assert_eq!(counter.count(), 8);
+    }
+
+    // Spare capacity ------------------------------------------------------------------------------
+
+    #[test]
+    fn spare_iter_mut() {
+        let mut vec = StaticVec::<i32, 4>::from_array([7]);
+        assert_eq!(vec.spare_iter_mut().len(), 3);
+
+        for (i, slot) in vec.spare_iter_mut().take(2).enumerate() {
+            slot.write(i as i32 * 10);
+        }
+
+        unsafe { vec.set_len(3) };
+        assert_eq!(vec.as_slice(), [7, 0, 10]);
+        assert_eq!(vec.spare_iter_mut().len(), 1);
+
+        vec.push(1);
+        assert_eq!(vec.spare_iter_mut().next().map(|_| ()), None);
+    }
+
+    // Align to ------------------------------------------------------------------------------------
+
+    #[test]
+    fn align_to() {
+        let vec = StaticVec::<u8, 32>::from_fn(|i| i as u8);
+        let (prefix, middle, suffix) = unsafe { vec.align_to::<u32>() };
+
+        assert!(prefix.len() < 4 && suffix.len() < 4);
+        assert_eq!(prefix.len() + middle.len() * 4 + suffix.len(), 32);
+        assert_eq!(middle.len(), if prefix.is_empty() { 8 } else { 7 });
+
+        // The elements are reinterpreted in place.
+        let first = u32::from_ne_bytes([0, 1, 2, 3].map(|b| b + prefix.len() as u8));
+        assert_eq!(middle[0], first);
+    }
+
+    // Push n --------------------------------------------------------------------------------------
+
+    #[test]
+    fn push_n() {
+        let mut vec = StaticVec::<i32, 5>::from_array([1]);
+
+        vec.push_n(9, 3);
+        assert_eq!(vec.as_slice(), [1, 9, 9, 9]);
+
+        vec.push_n(4, 0);
+        assert_eq!(vec.as_slice(), [1, 9, 9, 9]);
+
+        vec.push_n(4, 1);
+        assert_eq!(vec.as_slice(), [1, 9, 9, 9, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "CapacityOverflow")]
+    fn push_n_over_capacity() {
+        let mut vec = StaticVec::<i32, 4>::from_array([1, 2, 3]);
+        vec.push_n(0, 2);
+    }
+
+    #[test]
+    fn push_n_panicking_clone() {
+        let counter = DropCounter::new();
+        let mut vec = StaticVec::<_, 4>::from_array([counter.item(0)]);
+
+        let result =
+            panic::catch_unwind(AssertUnwindSafe(|| vec.push_n(counter.panicking_item(1), 3)));
+        assert!(result.is_err());
+
+        // The value is dropped and the vector is unchanged.
+        assert_eq!(counter.count(), 1);
+        assert_eq!(values(&vec), [0]);
+    }
+
+    // Prepend slice -------------------------------------------------------------------------------
+
+    #[test]
+    fn prepend_slice() {
+        let mut vec = StaticVec::<i32, 6>::from_array([2, 3]);
+
+        vec.prepend_slice(&[0, 1]);
+        assert_eq!(vec.as_slice(), [0, 1, 2, 3]);
+
+        vec.prepend_slice(&[]);
+        assert_eq!(vec.as_slice(), [0, 1, 2, 3]);
+
+        let mut empty = StaticVec::<i32, 2>::new();
+        empty.prepend_slice(&[5, 6]);
+        assert_eq!(empty.as_slice(), [5, 6]);
+    }
+
+    #[test]
+    #[should_panic(expected = "CapacityOverflow")]
+    fn prepend_slice_over_capacity() {
+        let mut vec = StaticVec::<i32, 4>::from_array([1, 2, 3]);
+        vec.prepend_slice(&[8, 9]);
+    }
+
+    #[test]
+    fn prepend_slice_panicking_clone() {
+        let counter = DropCounter::new();
+        let mut vec = StaticVec::<_, 4>::from_array([counter.item(2)]);
+        let src = [counter.item(0), counter.panicking_item(1)];
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| vec.prepend_slice(&src)));
+        assert!(result.is_err());
+
+        // The elements are leaked rather than dropped twice.
+        assert!(vec.is_empty());
+        assert_eq!(counter.count(), 0);
+
+        drop(src);
+        assert_eq!(counter.count(), 2);
+    }
+
+    // Checksum ------------------------------------------------------------------------------------
+
+    #[test]
+    fn checksum_u32() {
+        // The check values of CRC-32/ISO-HDLC.
+        assert_eq!(StaticVec::<u8, 16>::from_bytes(b"123456789").checksum_u32(), 0xCBF4_3926);
+        assert_eq!(StaticVec::<u8, 16>::new().checksum_u32(), 0);
+
+        let vec = StaticVec::<u8, 64>::from_bytes(b"The quick brown fox jumps over the lazy dog");
+        assert_eq!(vec.checksum_u32(), 0x414F_A339);
+    }
+
+    // Partition -----------------------------------------------------------------------------------
+
+    #[test]
+    fn partition() {
+        let vec = StaticVec::<i32, 6>::from_array([1, 2, 3, 4, 5, 6]);
+
+        let (even, odd) = vec.partition(|x| x % 2 == 0);
+        assert_eq!(even.as_slice(), [2, 4, 6]);
+        assert_eq!(odd.as_slice(), [1, 3, 5]);
+    }
+
+    #[test]
+    fn partition_moves_each_element_once() {
+        let counter = DropCounter::new();
+        let vec = StaticVec::<_, 4>::from_fn(|i| counter.item(i as i32));
+
+        let (low, high) = vec.partition(|item| item.value < 1);
+        assert_eq!(values(&low), [0]);
+        assert_eq!(values(&high), [1, 2, 3]);
+        assert_eq!(counter.count(), 0);
+
+        drop((low, high));
+        assert_eq!(counter.count(), 4);
+    }
+
+    // Reserve total -------------------------------------------------------------------------------
+
+    #[test]
+    fn reserve_total() {
+        let mut vec = StaticVec::<i32, 4>::from_array([1, 2]);
+
+        // The totals that are not more than the length don't need any space.
+        assert_eq!(vec.try_reserve_total(1), Ok(()));
+        assert_eq!(vec.try_reserve_total(2), Ok(()));
+        assert_eq!(vec.try_reserve_total(4), Ok(()));
+
+        let error = vec.try_reserve_total(5).unwrap_err();
+        assert_eq!(error.kind(), TryReserveErrorKind::CapacityOverflow);
+
+        vec.reserve_total(3);
+        assert_eq!(vec.as_slice(), [1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "CapacityOverflow")]
+    fn reserve_total_over_capacity() {
+        StaticVec::<i32, 4>::new().reserve_total(5);
+    }
+
+    // Clone into slice ----------------------------------------------------------------------------
+
+    #[test]
+    fn clone_into_slice() {
+        let vec = StaticVec::<i32, 4>::from_array([1, 2, 3]);
+
+        let mut longer = [0; 5];
+        assert_eq!(vec.clone_into_slice(&mut longer), 3);
+        assert_eq!(longer, [1, 2, 3, 0, 0]);
+
+        let mut exact = [0; 3];
+        assert_eq!(vec.clone_into_slice(&mut exact), 3);
+        assert_eq!(exact, [1, 2, 3]);
+
+        let mut shorter = [0; 2];
+        assert_eq!(vec.clone_into_slice(&mut shorter), 2);
+        assert_eq!(shorter, [1, 2]);
+    }
+
+    #[test]
+    fn clone_into_slice_drops_overwritten_elements() {
+        let counter = DropCounter::new();
+        let vec = StaticVec::<_, 4>::from_fn(|i| counter.item(i as i32));
+        let mut dst = [counter.item(10), counter.item(11)];
+
+        assert_eq!(vec.clone_into_slice(&mut dst), 2);
+        assert_eq!(values(&dst), [0, 1]);
+        assert_eq!(counter.count(), 2);
+    }
+
+    // Truncate at ---------------------------------------------------------------------------------
+
+    #[test]
+    fn truncate_at() {
+        let mut vec = StaticVec::<i32, 6>::from_array([1, 2, 0, 3, 0]);
+
+        vec.truncate_at(|x| *x == 5);
+        assert_eq!(vec.as_slice(), [1, 2, 0, 3, 0]);
+
+        // Only the first match counts.
+        vec.truncate_at(|x| *x == 0);
+        assert_eq!(vec.as_slice(), [1, 2]);
+
+        vec.truncate_at(|x| *x == 1);
+        assert!(vec.is_empty());
+    }
+
+    #[test]
+    fn truncate_at_drops_the_rest() {
+        let counter = DropCounter::new();
+        let mut vec = StaticVec::<_, 4>::from_fn(|i| counter.item(i as i32));
+
+        vec.truncate_at(|item| item.value == 1);
+        assert_eq!(values(&vec), [0]);
+        assert_eq!(counter.count(), 3);
+    }
+
+    // Common prefix -------------------------------------------------------------------------------
+
+    #[test]
+    fn common_prefix_len() {
+        let vec = StaticVec::<i32, 4>::from_array([1, 2, 3]);
+
+        assert_eq!(vec.common_prefix_len(&[1, 2, 4]), 2);
+        assert_eq!(vec.common_prefix_len(&[1, 2, 3, 4]), 3);
+        assert_eq!(vec.common_prefix_len(&[1]), 1);
+        assert_eq!(vec.common_prefix_len(&[9, 2, 3]), 0);
+        assert_eq!(vec.common_prefix_len(&[]), 0);
+        assert_eq!(StaticVec::<i32, 4>::new().common_prefix_len(&[1]), 0);
+    }
+
+    // Length-prefixed frames ----------------------------------------------------------------------
+
+    #[test]
+    fn length_prefixed_round_trip() {
+        let vec = StaticVec::<u8, 8>::from_bytes(b"abc");
+        let mut out = StaticVec::<u8, 16>::from_bytes(b"x");
+
+        vec.write_length_prefixed(&mut out).unwrap();
+        assert_eq!(out.as_slice(), [b'x', 0, 0, 0, 3, b'a', b'b', b'c']);
+
+        // The remaining bytes after the frame are not consumed.
+        out.push(b'y');
+        let (len, frame) = StaticVec::<u8, 8>::read_length_prefixed(&out[1..]).unwrap();
+        assert_eq!(len, 7);
+        assert_eq!(frame.as_slice(), b"abc");
+    }
+
+    #[test]
+    fn length_prefixed_errors() {
+        let frame = [0, 0, 0, 3, b'a', b'b', b'c'];
+
+        let result = StaticVec::<u8, 8>::read_length_prefixed(&frame[..6]);
+        assert_eq!(result.unwrap_err(), FrameError::Truncated);
+
+        let result = StaticVec::<u8, 8>::read_length_prefixed(&frame[..2]);
+        assert_eq!(result.unwrap_err(), FrameError::Truncated);
+
+        let result = StaticVec::<u8, 2>::read_length_prefixed(&frame);
+        assert_eq!(result.unwrap_err(), FrameError::CapacityExceeded);
+
+        // The output is unchanged if the whole frame doesn't fit.
+        let mut small = StaticVec::<u8, 6>::from_bytes(b"x");
+        let vec = StaticVec::<u8, 8>::from_bytes(b"abc");
+        assert!(vec.write_length_prefixed(&mut small).is_err());
+        assert_eq!(small.as_slice(), b"x");
+    }
+
+    // Insert sorted -------------------------------------------------------------------------------
+
+    #[test]
+    fn insert_sorted() {
+        let mut vec = StaticVec::<i32, 5>::new();
+
+        assert_eq!(vec.insert_sorted(3, DupPolicy::Allow), Ok(0));
+        assert_eq!(vec.insert_sorted(1, DupPolicy::Allow), Ok(0));
+        assert_eq!(vec.insert_sorted(2, DupPolicy::Reject), Ok(1));
+        assert_eq!(vec.insert_sorted(2, DupPolicy::Reject), Err(2));
+        assert_eq!(vec.insert_sorted(2, DupPolicy::Allow), Ok(2));
+        assert_eq!(vec.insert_sorted(9, DupPolicy::Allow), Ok(4));
+        assert_eq!(vec.as_slice(), [1, 2, 2, 3, 9]);
+
+        // The vector is full, but an equal element can still be replaced.
+        assert_eq!(vec.insert_sorted(0, DupPolicy::Allow), Err(0));
+        assert_eq!(vec.insert_sorted(3, DupPolicy::Allow), Err(3));
+        assert_eq!(vec.insert_sorted(9, DupPolicy::Replace), Ok(4));
+        assert_eq!(vec.as_slice(), [1, 2, 2, 3, 9]);
+    }
+
+    #[test]
+    fn insert_sorted_replace() {
+        /// An entry that is ordered by its key only.
+        #[derive(Debug)]
+        struct Entry(i32, &'static str);
+
+        impl PartialEq for Entry {
+            fn eq(&self, other: &Self) -> bool {
+                return self.0 == other.0;
+            }
+        }
+
+        impl Eq for Entry {}
+
+        impl PartialOrd for Entry {
+            fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+                return Some(self.cmp(other));
+            }
+        }
+
+        impl Ord for Entry {
+            fn cmp(&self, other: &Self) -> cmp::Ordering {
+                return self.0.cmp(&other.0);
+            }
+        }
+
+        let mut vec = StaticVec::<_, 4>::from_array([Entry(1, "a"), Entry(2, "b")]);
+
+        assert_eq!(vec.insert_sorted(Entry(2, "c"), DupPolicy::Replace).unwrap(), 1);
+        assert_eq!(vec.insert_sorted(Entry(3, "d"), DupPolicy::Replace).unwrap(), 2);
+
+        let tags = vec.iter().map(|entry| entry.1).collect::<std::vec::Vec<_>>();
+        assert_eq!(tags, ["a", "c", "d"]);
+    }
+
+    // Copy rotated --------------------------------------------------------------------------------
+
+    #[test]
+    fn copy_rotated_into() {
+        let vec = StaticVec::<i32, 5>::from_array([1, 2, 3, 4, 5]);
+        let mut out = StaticVec::<i32, 5>::from_array([9, 9]);
+
+        vec.copy_rotated_into(2, &mut out);
+        assert_eq!(out.as_slice(), [3, 4, 5, 1, 2]);
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4, 5]);
+
+        vec.copy_rotated_into(0, &mut out);
+        assert_eq!(out.as_slice(), [1, 2, 3, 4, 5]);
+
+        vec.copy_rotated_into(5, &mut out);
+        assert_eq!(out.as_slice(), [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index is out-of-range.")]
+    fn copy_rotated_into_out_of_range() {
+        let vec = StaticVec::<i32, 5>::from_array([1, 2, 3]);
+        let mut out = StaticVec::<i32, 5>::new();
+
+        vec.copy_rotated_into(4, &mut out);
+    }
+
+    // Map in place --------------------------------------------------------------------------------
+
+    #[test]
+    fn map_in_place() {
+        let mut vec = StaticVec::<i32, 4>::from_array([1, 2, 3]);
+        let mut visited = 0;
+
+        vec.map_in_place(|x| {
+            *x *= 10;
+            visited += 1;
+        });
+
+        assert_eq!(vec.as_slice(), [10, 20, 30]);
+        assert_eq!(visited, 3);
+
+        StaticVec::<i32, 4>::new().map_in_place(|_| panic!("Vector is empty."));
+    }
+
+    // Recapacity ----------------------------------------------------------------------------------
+
+    #[test]
+    fn try_recapacity_moves_elements_once() {
+        let counter = DropCounter::new();
+        let vec = StaticVec::<_, 4>::from_fn(|i| counter.item(i as i32));
+
+        let smaller = vec.try_recapacity::<2>().unwrap_err();
+        assert_eq!(values(&smaller), [0, 1, 2, 3]);
+        assert_eq!(counter.count(), 0);
+
+        let larger = smaller.try_recapacity::<8>().unwrap();
+        assert_eq!(larger.capacity(), 8);
+        assert_eq!(values(&larger), [0, 1, 2, 3]);
+        assert_eq!(counter.count(), 0);
+
+        let exact = larger.try_recapacity::<4>().unwrap();
+        assert_eq!(values(&exact), [0, 1, 2, 3]);
+        assert_eq!(counter.count(), 0);
+
+        drop(exact);
+        assert_eq!(counter.count(), 4);
+    }
+
+    // Intersect sorted ----------------------------------------------------------------------------
+
+    #[test]
+    fn intersect_sorted() {
+        let a = StaticVec::<i32, 6>::from_array([1, 2, 2, 4, 7, 9]);
+        let b = StaticVec::<i32, 5>::from_array([2, 2, 3, 7, 10]);
+        let mut out = StaticVec::<i32, 4>::from_array([0]);
+
+        assert_eq!(a.intersect_sorted(&b, &mut out), Ok(()));
+        assert_eq!(out.as_slice(), [0, 2, 2, 7]);
+
+        let mut out = StaticVec::<i32, 4>::new();
+        assert_eq!(a.intersect_sorted(&StaticVec::<i32, 1>::new(), &mut out), Ok(()));
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn intersect_sorted_out_of_capacity() {
+        let a = StaticVec::<i32, 4>::from_array([1, 2, 3, 4]);
+        let mut out = StaticVec::<i32, 2>::new();
+
+        assert!(a.intersect_sorted(&a, &mut out).is_err());
+        assert_eq!(out.as_slice(), [1, 2]);
+    }
+
+    // Swap ranges ---------------------------------------------------------------------------------
+
+    #[test]
+    fn swap_ranges() {
+        let mut vec = StaticVec::<i32, 8>::from_array([0, 1, 2, 3, 4, 5, 6]);
+
+        vec.swap_ranges(0..2, 4..6);
+        assert_eq!(vec.as_slice(), [4, 5, 2, 3, 0, 1, 6]);
+
+        vec.swap_ranges(6..7, 2..3);
+        assert_eq!(vec.as_slice(), [4, 5, 6, 3, 0, 1, 2]);
+
+        vec.swap_ranges(3..3, 3..3);
+        assert_eq!(vec.as_slice(), [4, 5, 6, 3, 0, 1, 2]);
+    }
+
+    #[test]
+    fn swap_ranges_keeps_elements() {
+        let counter = DropCounter::new();
+        let mut vec = StaticVec::<_, 4>::from_fn(|i| counter.item(i as i32));
+
+        vec.swap_ranges(2..4, 0..2);
+        assert_eq!(values(&vec), [2, 3, 0, 1]);
+        assert_eq!(counter.count(), 0);
+
+        drop(vec);
+        assert_eq!(counter.count(), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index is out-of-range.")]
+    fn swap_ranges_out_of_range() {
+        StaticVec::<i32, 8>::from_array([0, 1, 2, 3]).swap_ranges(0..2, 3..5);
+    }
+
+    #[test]
+    #[should_panic(expected = "Ranges have different lengths.")]
+    fn swap_ranges_different_lengths() {
+        StaticVec::<i32, 8>::from_array([0, 1, 2, 3]).swap_ranges(0..1, 2..4);
+    }
+
+    #[test]
+    #[should_panic(expected = "Ranges overlap.")]
+    fn swap_ranges_overlap() {
+        StaticVec::<i32, 8>::from_array([0, 1, 2, 3]).swap_ranges(0..2, 1..3);
+    }
+
+    // From C string bytes -------------------------------------------------------------------------
+
+    #[test]
+    fn from_cstr_bytes() {
+        let vec = StaticVec::<u8, 5>::from_cstr_bytes(b"hello\0world\0").unwrap();
+        assert_eq!(vec.as_slice(), b"hello");
+
+        assert!(StaticVec::<u8, 0>::from_cstr_bytes(b"\0abc").unwrap().is_empty());
+
+        assert_eq!(
+            StaticVec::<u8, 8>::from_cstr_bytes(b"hello"),
+            Err(FromBytesError::NotNulTerminated)
+        );
+        assert_eq!(
+            StaticVec::<u8, 4>::from_cstr_bytes(b"hello\0"),
+            Err(FromBytesError::CapacityExceeded)
+        );
+    }
+
+    // Extend saturating ---------------------------------------------------------------------------
+
+    #[test]
+    fn extend_saturating() {
+        let mut vec = StaticVec::<i32, 4>::from_array([1]);
+
+        assert_eq!(vec.extend_saturating(&[2, 3]), 2);
+        assert_eq!(vec.extend_saturating(&[4, 5, 6]), 1);
+        assert_eq!(vec.extend_saturating(&[7]), 0);
+        assert_eq!(vec.extend_saturating(&[]), 0);
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4]);
+    }
+
+    // Replace -------------------------------------------------------------------------------------
+
+    #[test]
+    fn replace() {
+        let counter = DropCounter::new();
+        let mut vec = StaticVec::<_, 4>::from_fn(|i| counter.item(i as i32));
+
+        let old = vec.replace(1, counter.item(9));
+        assert_eq!(old.value, 1);
+        assert_eq!(values(&vec), [0, 9, 2, 3]);
+        assert_eq!(counter.count(), 0);
+
+        drop(old);
+        drop(vec);
+        assert_eq!(counter.count(), 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index is out-of-range.")]
+    fn replace_out_of_range() {
+        StaticVec::<i32, 4>::from_array([1, 2]).replace(2, 0);
+    }
+
+    // Push window ---------------------------------------------------------------------------------
+
+    #[test]
+    fn push_window() {
+        let mut vec = StaticVec::<i32, 3>::new();
+
+        assert_eq!(vec.push_window(1), None);
+        assert_eq!(vec.push_window(2), None);
+        assert_eq!(vec.push_window(3), None);
+        assert_eq!(vec.push_window(4), Some(1));
+        assert_eq!(vec.push_window(5), Some(2));
+        assert_eq!(vec.as_slice(), [3, 4, 5]);
+
+        let mut empty = StaticVec::<i32, 0>::new();
+        assert_eq!(empty.push_window(1), Some(1));
+        assert!(empty.is_empty());
+    }
+
+    // Fold ----------------------------------------------------------------------------------------
+
+    #[test]
+    fn fold() {
+        let vec = StaticVec::<i32, 4>::from_array([1, 2, 3]);
+
+        assert_eq!(vec.fold(0, |acc, x| acc + x), 6);
+        assert_eq!(vec.fold(0, |acc, x| acc * 10 + x), 123);
+        assert_eq!(StaticVec::<i32, 4>::new().fold(7, |acc, x| acc + x), 7);
+    }
+
+    // Resize with ---------------------------------------------------------------------------------
+
+    #[test]
+    fn resize_with() {
+        let mut vec = StaticVec::<i32, 6>::from_array([1, 2, 3]);
+
+        vec.resize_with(5, || 7);
+        assert_eq!(vec.as_slice(), [1, 2, 3, 7, 7]);
+
+        vec.resize_with(2, || unreachable!());
+        assert_eq!(vec.as_slice(), [1, 2]);
+    }
+
+    // Bitset --------------------------------------------------------------------------------------
+
+    #[test]
+    fn set_clear_get_bit() {
+        let mut bits = StaticVec::<u8, 4>::new();
+
+        bits.set_bit(9);
+        assert_eq!(bits.as_slice(), [0x00, 0x02]);
+
+        bits.set_bit(0);
+        bits.set_bit(7);
+        assert_eq!(bits.as_slice(), [0x81, 0x02]);
+        assert!(bits.get_bit(0) && bits.get_bit(7) && bits.get_bit(9));
+        assert!(!bits.get_bit(1) && !bits.get_bit(8) && !bits.get_bit(31));
+
+        bits.clear_bit(7);
+        bits.clear_bit(30);
+        assert_eq!(bits.as_slice(), [0x01, 0x02]);
+        assert!(!bits.get_bit(7));
+    }
+
+    #[test]
+    #[should_panic(expected = "CapacityOverflow")]
+    fn set_bit_out_of_capacity() {
+        StaticVec::<u8, 2>::new().set_bit(16);
+    }
+
+    // Extend --------------------------------------------------------------------------------------
+
+    #[test]
+    fn extend() {
+        let mut vec = StaticVec::<i32, 6>::from_array([1]);
+
+        vec.extend([2, 3]);
+        vec.extend((4..10).filter(|x| x % 2 == 0));
+        Extend::extend(&mut vec, core::iter::empty());
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4, 6, 8]);
+    }
+
+    #[test]
+    #[should_panic(expected = "CapacityOverflow")]
+    fn extend_out_of_capacity() {
+        StaticVec::<i32, 2>::new().extend([1, 2, 3]);
+    }
+
+    // Content hash --------------------------------------------------------------------------------
+
+    #[test]
+    fn content_hash() {
+        let a = StaticVec::<u32, 4>::from_array([1, 2, 3]);
+        let b = StaticVec::<u32, 8>::from_array([1, 2, 3]);
+
+        assert_eq!(a.content_hash(), b.content_hash());
+
+        assert_ne!(a.content_hash(), StaticVec::<u32, 4>::from_array([3, 2, 1]).content_hash());
+        assert_ne!(a.content_hash(), StaticVec::<u32, 4>::from_array([1, 2]).content_hash());
+        assert_ne!(a.content_hash(), StaticVec::<u32, 4>::new().content_hash());
+    }
+
+    // Borrowed iteration --------------------------------------------------------------------------
+
+    #[test]
+    fn iter_and_iter_mut() {
+        let mut vec = StaticVec::<i32, 4>::from_array([1, 2, 3]);
+
+        assert_eq!(vec.iter().rev().copied().collect::<std::vec::Vec<_>>(), [3, 2, 1]);
+
+        for x in vec.iter_mut() {
+            *x += 1;
+        }
+
+        for x in &mut vec {
+            *x *= 2;
+        }
+
+        let mut sum = 0;
+
+        for x in &vec {
+            sum += x;
+        }
+
+        assert_eq!(vec.as_slice(), [4, 6, 8]);
+        assert_eq!(sum, 18);
+        assert_eq!(StaticVec::<i32, 4>::new().iter().next(), None);
+    }
+
+    // Min max -------------------------------------------------------------------------------------
+
+    #[test]
+    fn min_max() {
+        assert_eq!(StaticVec::<i32, 4>::new().min_max(), None);
+        assert_eq!(StaticVec::<i32, 4>::from_array([5]).min_max(), Some((&5, &5)));
+        assert_eq!(StaticVec::<i32, 4>::from_array([4, 1, 9]).min_max(), Some((&1, &9)));
+        assert_eq!(StaticVec::<i32, 4>::from_array([9, 4, 1, 7]).min_max(), Some((&1, &9)));
+    }
+
+    #[test]
+    fn min_max_ties() {
+        for len in 1..=5 {
+            let mut vec = StaticVec::<i32, 5>::new();
+            vec.resize_with(len, || 3);
+
+            let (min, max) = vec.min_max().unwrap();
+            assert!(ptr::eq(min, &vec[0]));
+            assert!(ptr::eq(max, &vec[len - 1]));
+        }
+    }
+
+    // Deref ---------------------------------------------------------------------------------------
+
+    #[test]
+    fn deref_to_slice() {
+        let mut vec = StaticVec::<i32, 4>::from_array([3, 1, 2]);
+
+        assert_eq!(vec.len(), 3);
+        assert_eq!(vec[1], 1);
+        assert_eq!(vec.first(), Some(&3));
+        assert_eq!(&vec[1..], [1, 2]);
+
+        vec[0] = 4;
+        vec.reverse();
+        assert_eq!(vec.as_slice(), [2, 1, 4]);
+
+        fn sum(slice: &[i32]) -> i32 {
+            return slice.iter().sum();
+        }
+
+        assert_eq!(sum(&vec), 7);
+    }
+
+    // Debug ---------------------------------------------------------------------------------------
+
+    #[test]
+    fn debug() {
+        assert_eq!(std::format!("{:?}", StaticVec::<i32, 4>::from_array([1, 2])), "[1, 2]");
+        assert_eq!(std::format!("{:?}", StaticVec::<&str, 4>::new()), "[]");
+        assert_eq!(
+            std::format!("{:#?}", StaticVec::<u8, 4>::from_array([7])),
+            std::format!("{:#?}", [7u8])
+        );
+    }
+
+    // Endian bytes --------------------------------------------------------------------------------
+
+    #[test]
+    fn to_and_from_endian_bytes() {
+        let vec = StaticVec::<u16, 4>::from_array([0x0102, 0x0304]);
+
+        let le = vec.to_le_bytes::<4>().unwrap();
+        let be = vec.to_be_bytes::<8>().unwrap();
+        assert_eq!(le.as_slice(), [0x02, 0x01, 0x04, 0x03]);
+        assert_eq!(be.as_slice(), [0x01, 0x02, 0x03, 0x04]);
+
+        assert_eq!(StaticVec::<u16, 4>::from_le_bytes(&le).unwrap(), vec);
+        assert_eq!(StaticVec::<u16, 2>::from_be_bytes(&be).unwrap(), vec);
+
+        let vec = StaticVec::<i64, 2>::from_array([-1, i64::MIN]);
+        let bytes = vec.to_le_bytes::<16>().unwrap();
+        assert_eq!(StaticVec::<i64, 2>::from_le_bytes(&bytes).unwrap(), vec);
+    }
+
+    #[test]
+    fn endian_bytes_errors() {
+        let vec = StaticVec::<u32, 4>::from_array([1, 2]);
+        assert!(vec.to_le_bytes::<7>().is_err());
+
+        assert_eq!(StaticVec::<u32, 4>::from_le_bytes(&[0; 6]), Err(FrameError::Truncated));
+        assert_eq!(StaticVec::<u32, 1>::from_be_bytes(&[0; 8]), Err(FrameError::CapacityExceeded));
+        assert!(StaticVec::<u32, 1>::from_be_bytes(&[]).unwrap().is_empty());
+    }
+
+    // Comparison ----------------------------------------------------------------------------------
+
+    #[test]
+    fn partial_eq() {
+        let vec = StaticVec::<i32, 4>::from_array([1, 2]);
+
+        assert_eq!(vec, StaticVec::<i32, 8>::from_array([1, 2]));
+        assert_ne!(vec, StaticVec::<i32, 4>::from_array([1, 2, 3]));
+        assert!(vec == [1, 2]);
+        assert!(vec == *[1, 2].as_slice());
+        assert!(vec == [1, 2].as_slice());
+        assert!(vec != [2, 1]);
+    }
+
+    #[test]
+    fn ordering() {
+        let a = StaticVec::<i32, 4>::from_array([1, 2]);
+        let b = StaticVec::<i32, 4>::from_array([1, 3]);
+        let c = StaticVec::<i32, 4>::from_array([1, 2, 0]);
+
+        assert!(a < b);
+        assert!(a < c);
+        assert!(c < b);
+        assert!(StaticVec::<i32, 8>::new() < a);
+        assert_eq!(a.cmp(&a.clone()), cmp::Ordering::Equal);
+        assert_eq!(
+            a.partial_cmp(&StaticVec::<i32, 2>::from_array([1, 1])),
+            Some(cmp::Ordering::Greater)
+        );
+
+        let mut vecs = [b.clone(), c.clone(), a.clone()];
+        vecs.sort();
+        assert_eq!(vecs, [a, c, b]);
+    }
+
+    // Hash ----------------------------------------------------------------------------------------
+
+    #[test]
+    fn hash_matches_slice() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::collections::HashSet;
+
+        fn hash_of<H: hash::Hash + ?Sized>(value: &H) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+
+            return hash::Hasher::finish(&hasher);
+        }
+
+        let vec = StaticVec::<u8, 4>::from_array([1, 2, 3]);
+        assert_eq!(hash_of(&vec), hash_of([1u8, 2, 3].as_slice()));
+
+        // `Borrow<[T]>` allows looking up by slice.
+        let mut set = HashSet::new();
+        set.insert(vec);
+
+        assert!(set.contains([1u8, 2, 3].as_slice()));
+        assert!(!set.contains([1u8, 2].as_slice()));
+    }
+
+    // Sort dedup ----------------------------------------------------------------------------------
+
+    #[test]
+    fn sort_dedup() {
+        let mut vec = StaticVec::<i32, 8>::from_array([3, 1, 3, 2, 1, 3]);
+
+        vec.sort_dedup();
+        assert_eq!(vec.as_slice(), [1, 2, 3]);
+
+        let mut empty = StaticVec::<i32, 8>::new();
+        empty.sort_dedup();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn dedup_by_drops_duplicates_once() {
+        let counter = DropCounter::new();
+        let mut vec = StaticVec::<_, 8>::new();
+
+        for value in [1, 1, 2, 3, 3, 3, 1] {
+            vec.push(counter.item(value));
+        }
+
+        vec.dedup_by(|a, b| a.value == b.value);
+        assert_eq!(values(&vec), [1, 2, 3, 1]);
+        assert_eq!(counter.count(), 3);
+
+        vec.dedup_by_key(|x| x.value % 2);
+        assert_eq!(values(&vec), [1, 2, 3]);
+        assert_eq!(counter.count(), 4);
+
+        drop(vec);
+        assert_eq!(counter.count(), 7);
+    }
+
+    // From array ----------------------------------------------------------------------------------
+
+    #[test]
+    fn from_array_and_from_fn() {
+        const EXACT: StaticVec<u8, 3> = StaticVec::from_exact_array([1, 2, 3]);
+        assert_eq!(EXACT.as_slice(), [1, 2, 3]);
+
+        let counter = DropCounter::new();
+
+        let vec = StaticVec::<_, 4>::from_array([counter.item(1), counter.item(2)]);
+        assert_eq!(values(&vec), [1, 2]);
+        assert_eq!(counter.count(), 0);
+
+        drop(vec);
+        assert_eq!(counter.count(), 2);
+
+        assert_eq!(StaticVec::<usize, 4>::from_fn(|i| i * 10).as_slice(), [0, 10, 20, 30]);
+        assert!(StaticVec::<usize, 0>::from_fn(|_| unreachable!()).is_empty());
+    }
+
+    #[test]
+    fn from_array_too_long_drops_elements() {
+        let counter = DropCounter::new();
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            StaticVec::<_, 1>::from_array([counter.item(1), counter.item(2)])
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(counter.count(), 2);
+    }
+
+    #[test]
+    fn from_fn_panic_drops_created_elements() {
+        let counter = DropCounter::new();
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            StaticVec::<_, 4>::from_fn(|i| {
+                assert!(i < 2, "Element cannot be created.");
+                return counter.item(i as i32);
+            })
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(counter.count(), 2);
+    }
+
+    // Truncate LIFO -------------------------------------------------------------------------------
+
+    /// An element that records the order in which the elements are dropped.
+    struct Ordered<'a> {
+        value: i32,
+        log: &'a core::cell::RefCell<std::vec::Vec<i32>>,
+        panic_on_drop: bool,
+    }
+
+    impl Drop for Ordered<'_> {
+        fn drop(&mut self) {
+            self.log.borrow_mut().push(self.value);
+
+            if self.panic_on_drop {
+                panic!("Element panics on drop.");
+            }
+        }
+    }
+
+    #[test]
+    fn truncate_lifo_and_clear_lifo() {
+        let log = core::cell::RefCell::new(std::vec::Vec::new());
+        let mut vec = StaticVec::<_, 5>::from_fn(|i| Ordered {
+            value: i as i32,
+            log: &log,
+            panic_on_drop: false,
+        });
+
+        vec.truncate_lifo(2);
+        assert_eq!(vec.len(), 2);
+        assert_eq!(*log.borrow(), [4, 3, 2]);
+
+        vec.truncate_lifo(3);
+        assert_eq!(vec.len(), 2);
+
+        vec.clear_lifo();
+        assert!(vec.is_empty());
+        assert_eq!(*log.borrow(), [4, 3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn truncate_lifo_panicking_drop() {
+        let log = core::cell::RefCell::new(std::vec::Vec::new());
+        let mut vec = StaticVec::<_, 4>::from_fn(|i| Ordered {
+            value: i as i32,
+            log: &log,
+            panic_on_drop: i == 2,
+        });
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| vec.truncate_lifo(0)));
+        assert!(result.is_err());
+
+        // The panicking element is not dropped again with the vector,
+        // which drops the remaining elements from the first to the last.
+        assert_eq!(vec.len(), 2);
+        drop(vec);
+        assert_eq!(*log.borrow(), [3, 2, 0, 1]);
+    }
+
+    // Repeat pattern ------------------------------------------------------------------------------
+
+    #[test]
+    fn repeat_pattern() {
+        let vec = StaticVec::<i32, 6>::repeat_pattern(&[1, 2], 3).unwrap();
+        assert_eq!(vec.as_slice(), [1, 2, 1, 2, 1, 2]);
+
+        assert!(StaticVec::<i32, 6>::repeat_pattern(&[1, 2], 0).unwrap().is_empty());
+        assert!(StaticVec::<i32, 6>::repeat_pattern(&[], 100).unwrap().is_empty());
+
+        assert!(StaticVec::<i32, 5>::repeat_pattern(&[1, 2], 3).is_err());
+        assert!(StaticVec::<i32, 5>::repeat_pattern(&[1, 2], usize::MAX).is_err());
+    }
+
+    // Split off -----------------------------------------------------------------------------------
+
+    #[test]
+    fn split_off() {
+        let counter = DropCounter::new();
+        let mut vec = StaticVec::<_, 5>::from_fn(|i| counter.item(i as i32));
+
+        let tail = vec.split_off(3);
+        assert_eq!(values(&vec), [0, 1, 2]);
+        assert_eq!(values(&tail), [3, 4]);
+
+        let empty = vec.split_off(3);
+        assert!(empty.is_empty());
+
+        let all = vec.split_off(0);
+        assert!(vec.is_empty());
+        assert_eq!(values(&all), [0, 1, 2]);
+        assert_eq!(counter.count(), 0);
+
+        drop(all);
+        drop(tail);
+        assert_eq!(counter.count(), 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index is out-of-range.")]
+    fn split_off_out_of_range() {
+        StaticVec::<i32, 4>::from_array([1, 2]).split_off(3);
+    }
+
+    // Interleave ----------------------------------------------------------------------------------
+
+    #[test]
+    fn interleave() {
+        let a = StaticVec::<i32, 4>::from_array([1, 3, 5, 7]);
+        let b = StaticVec::<i32, 2>::from_array([2, 4]);
+        let mut out = StaticVec::<i32, 8>::from_array([0]);
+
+        assert_eq!(StaticVec::interleave(&a, &b, &mut out), Ok(()));
+        assert_eq!(out.as_slice(), [0, 1, 2, 3, 4, 5, 7]);
+
+        let mut out = StaticVec::<i32, 8>::new();
+        assert_eq!(StaticVec::interleave(&b, &a, &mut out), Ok(()));
+        assert_eq!(out.as_slice(), [2, 1, 4, 3, 5, 7]);
+    }
+
+    #[test]
+    fn interleave_out_of_capacity() {
+        let a = StaticVec::<i32, 2>::from_array([1, 3]);
+        let b = StaticVec::<i32, 2>::from_array([2, 4]);
+        let mut out = StaticVec::<i32, 3>::new();
+
+        assert!(StaticVec::interleave(&a, &b, &mut out).is_err());
+        assert_eq!(out.as_slice(), [1, 2, 3]);
+    }
+
+    // Windowed reduce -----------------------------------------------------------------------------
+
+    #[test]
+    fn windowed_reduce() {
+        let vec = StaticVec::<i32, 5>::from_array([1, 2, 3, 4, 5]);
+        let mut sums = StaticVec::<i32, 5>::new();
+
+        vec.windowed_reduce::<3, _, _>(|window| window.iter().sum(), &mut sums).unwrap();
+        assert_eq!(sums.as_slice(), [6, 9, 12]);
+
+        let short = StaticVec::<i32, 5>::from_array([1, 2]);
+        let mut sums = StaticVec::<i32, 5>::new();
+
+        short.windowed_reduce::<3, _, _>(|_| unreachable!(), &mut sums).unwrap();
+        assert!(sums.is_empty());
+    }
+
+    #[test]
+    fn windowed_reduce_out_of_capacity() {
+        let vec = StaticVec::<i32, 5>::from_array([1, 2, 3, 4, 5]);
+        let mut sums = StaticVec::<i32, 5>::from_array([0, 0, 0]);
+
+        let result = vec.windowed_reduce::<2, _, _>(|window| window.iter().sum(), &mut sums);
+        assert_eq!(result.unwrap_err().kind(), TryReserveErrorKind::CapacityOverflow);
+        assert_eq!(sums.as_slice(), [0, 0, 0, 3, 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Window size is zero.")]
+    fn windowed_reduce_zero_window() {
+        let vec = StaticVec::<i32, 2>::from_array([1, 2]);
+        vec.windowed_reduce::<0, _, _>(|_| 0, &mut StaticVec::new()).unwrap();
+    }
+
+    // Rotate to front -----------------------------------------------------------------------------
+
+    #[test]
+    fn rotate_to_front() {
+        let mut vec = StaticVec::<i32, 5>::from_array([1, 2, 3, 4, 5]);
+
+        vec.rotate_to_front(3);
+        assert_eq!(vec.as_slice(), [4, 5, 1, 2, 3]);
+
+        vec.rotate_to_front(0);
+        assert_eq!(vec.as_slice(), [4, 5, 1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index is out-of-range.")]
+    fn rotate_to_front_out_of_range() {
+        StaticVec::<i32, 4>::from_array([1, 2]).rotate_to_front(2);
+    }
+
+    // Push within capacity ------------------------------------------------------------------------
+
+    #[test]
+    fn push_within_capacity() {
+        let counter = DropCounter::new();
+        let mut vec = StaticVec::<_, 2>::new();
+
+        assert!(vec.push_within_capacity(counter.item(1)).is_ok());
+        assert!(vec.push_within_capacity(counter.item(2)).is_ok());
+
+        let rejected = vec.push_within_capacity(counter.item(3)).unwrap_err();
+        assert_eq!(rejected.value, 3);
+        assert_eq!(values(&vec), [1, 2]);
+        assert_eq!(counter.count(), 0);
+
+        drop(rejected);
+        drop(vec);
+        assert_eq!(counter.count(), 3);
+
+        assert_eq!(StaticVec::<i32, 0>::new().push_within_capacity(1), Err(1));
+    }
+
+    // Reset ---------------------------------------------------------------------------------------
+
+    #[test]
+    fn reset_zeroes_buffer() {
+        let mut vec = StaticVec::<u8, 4>::from_array([1, 2, 3]);
+
+        vec.reset();
+        assert!(vec.is_empty());
+
+        // The whole buffer has just been written with zero bytes.
+        let buffer = unsafe { slice::from_raw_parts(vec.as_ptr(), 4) };
+        assert_eq!(buffer, [0; 4]);
+    }
+
+    #[test]
+    fn reset_with_moves_elements_out() {
+        let counter = DropCounter::new();
+        let mut vec = StaticVec::<_, 4>::from_fn(|i| counter.item(i as i32));
+        let mut released = std::vec::Vec::new();
+
+        vec.reset_with(false, |item| released.push(item));
+        assert!(vec.is_empty());
+        assert_eq!(values(&released), [0, 1, 2, 3]);
+        assert_eq!(counter.count(), 0);
+
+        vec.push(counter.item(4));
+        vec.reset_with(true, drop);
+        assert!(vec.is_empty());
+        assert_eq!(counter.count(), 1);
+
+        drop(released);
+        assert_eq!(counter.count(), 5);
+    }
+
+    // Format write --------------------------------------------------------------------------------
+
+    #[test]
+    fn fmt_write() {
+        use core::fmt::Write;
+
+        let mut vec = StaticVec::<u8, 8>::new();
+
+        let ch = 'é';
+        write!(vec, "{}+{}", 12, ch).unwrap();
+        assert_eq!(vec.as_str(), Ok("12+é"));
+
+        // A string that doesn't fit is not written partially.
+        assert!(vec.write_str("abcd").is_err());
+        assert_eq!(vec.as_str(), Ok("12+é"));
+
+        assert!(vec.write_str("abc").is_ok());
+        assert_eq!(vec.as_str(), Ok("12+éabc"));
+    }
+
+    // Prefix sum ----------------------------------------------------------------------------------
+
+    #[test]
+    fn prefix_sum() {
+        let vec = StaticVec::<i32, 4>::from_array([1, 2, 3, -4]);
+        let mut out = StaticVec::<i32, 5>::from_array([9]);
+
+        assert_eq!(vec.prefix_sum(&mut out), Ok(()));
+        assert_eq!(out.as_slice(), [9, 1, 3, 6, 2]);
+
+        let mut out = StaticVec::<i32, 2>::new();
+        assert!(vec.prefix_sum(&mut out).is_err());
+        assert_eq!(out.as_slice(), [1, 3]);
+
+        let mut out = StaticVec::<i32, 2>::new();
+        assert_eq!(StaticVec::<i32, 4>::new().prefix_sum(&mut out), Ok(()));
+        assert!(out.is_empty());
+    }
+
+    // Try reserve error ---------------------------------------------------------------------------
+
+    #[test]
+    fn try_reserve_error_display() {
+        use std::string::ToString;
+
+        let error = StaticVec::<u8, 2>::new().try_reserve(3).unwrap_err();
+        assert_eq!(error.kind(), TryReserveErrorKind::CapacityOverflow);
+        assert_eq!(
+            error.to_string(),
+            "memory allocation failed because the computed capacity exceeded the collection's maximum"
+        );
+
+        let error = TryReserveError::from(TryReserveErrorKind::AllocError);
+        assert_eq!(
+            error.to_string(),
+            "memory allocation failed because the memory allocator returned an error"
+        );
+
+        // The messages are the same as the ones of `std`.
+        let std_error = std::vec::Vec::<u8>::new().try_reserve(usize::MAX).unwrap_err();
+        assert_eq!(
+            std_error.to_string(),
+            TryReserveError::from(TryReserveErrorKind::CapacityOverflow).to_string()
+        );
+
+        let source: &dyn error::Error = &error;
+        assert!(source.source().is_none());
+    }
+
+    // Reverse range -------------------------------------------------------------------------------
+
+    #[test]
+    fn reverse_range() {
+        let mut vec = StaticVec::<i32, 6>::from_array([1, 2, 3, 4, 5, 6]);
+
+        vec.reverse_range(1..5);
+        assert_eq!(vec.as_slice(), [1, 5, 4, 3, 2, 6]);
+
+        vec.reverse_range(6..6);
+        vec.reverse_range(0..1);
+        assert_eq!(vec.as_slice(), [1, 5, 4, 3, 2, 6]);
+
+        vec.reverse_range(0..6);
+        assert_eq!(vec.as_slice(), [6, 2, 3, 4, 5, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index is out-of-range.")]
+    fn reverse_range_out_of_range() {
+        StaticVec::<i32, 6>::from_array([1, 2, 3]).reverse_range(1..4);
+    }
+
+    // Reserve overflow ----------------------------------------------------------------------------
+
+    #[test]
+    fn try_reserve_overflow() {
+        let mut vec = StaticVec::<u8, 4>::from_array([1, 2]);
+
+        assert!(vec.try_reserve(2).is_ok());
+        assert!(vec.try_reserve(3).is_err());
+
+        // `len + additional` would wrap around to a small number without the overflow check.
+        let error = vec.try_reserve(usize::MAX - 1).unwrap_err();
+        assert_eq!(error.kind(), TryReserveErrorKind::CapacityOverflow);
+        assert!(vec.try_reserve(usize::MAX).is_err());
+
+        assert_eq!(vec.as_slice(), [1, 2]);
+    }
+
+    // Try map in place ----------------------------------------------------------------------------
+
+    #[test]
+    fn try_map_in_place() {
+        let mut vec = StaticVec::<i32, 4>::from_array([1, 2, 3]);
+
+        let result = vec.try_map_in_place(|x| -> Result<(), ()> {
+            *x *= 2;
+            return Ok(());
+        });
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(vec.as_slice(), [2, 4, 6]);
+
+        // The elements before the failed one stay transformed and the rest are not visited.
+        let result = vec.try_map_in_place(|x| {
+            if *x == 4 {
+                return Err(*x);
+            }
+
+            *x += 1;
+            return Ok(());
+        });
+
+        assert_eq!(result, Err(4));
+        assert_eq!(vec.as_slice(), [3, 4, 6]);
+    }
+
+    // Contains ------------------------------------------------------------------------------------
+
+    #[test]
+    fn contains_starts_with_ends_with() {
+        let vec = StaticVec::<i32, 4>::from_array([1, 2, 3]);
+
+        assert!(vec.contains(&2));
+        assert!(!vec.contains(&4));
+
+        assert!(vec.starts_with(&[]));
+        assert!(vec.starts_with(&[1, 2]));
+        assert!(!vec.starts_with(&[2]));
+        assert!(!vec.starts_with(&[1, 2, 3, 4]));
+
+        assert!(vec.ends_with(&[]));
+        assert!(vec.ends_with(&[2, 3]));
+        assert!(vec.ends_with(&[1, 2, 3]));
+        assert!(!vec.ends_with(&[1, 3]));
+
+        assert!(!StaticVec::<i32, 4>::new().contains(&0));
+    }
+
+    // Split by ------------------------------------------------------------------------------------
+
+    #[test]
+    fn split_by() {
+        let vec = StaticVec::<u8, 8>::from_bytes(b",ab,,c,");
+        let parts = vec.split_by(&b',').collect::<std::vec::Vec<_>>();
+
+        assert_eq!(parts, [&b""[..], b"ab", b"", b"c", b""]);
+
+        let vec = StaticVec::<u8, 8>::from_bytes(b"abc");
+        assert_eq!(vec.split_by(&b',').collect::<std::vec::Vec<_>>(), [b"abc"]);
+
+        let empty = StaticVec::<u8, 8>::new();
+        assert_eq!(empty.split_by(&b',').count(), 1);
+    }
+
+    // Binary search -------------------------------------------------------------------------------
+
+    #[test]
+    fn binary_search() {
+        let vec = StaticVec::<u32, 16>::from_array([1, 3, 5, 7]);
+
+        assert_eq!(CommonVec::binary_search(&vec, &5), Ok(2));
+        assert_eq!(CommonVec::binary_search(&vec, &0), Err(0));
+        assert_eq!(CommonVec::binary_search(&vec, &4), Err(2));
+        assert_eq!(CommonVec::binary_search(&vec, &8), Err(4));
+        assert_eq!(CommonVec::binary_search(&StaticVec::<u32, 16>::new(), &1), Err(0));
+
+        assert_eq!(CommonVec::binary_search_by(&vec, |x| x.cmp(&7)), Ok(3));
+        assert_eq!(CommonVec::binary_search_by(&vec, |x| x.cmp(&2)), Err(1));
+
+        let pairs = StaticVec::<(u32, char), 16>::from_array([(1, 'a'), (4, 'b'), (9, 'c')]);
+        assert_eq!(CommonVec::binary_search_by_key(&pairs, &4, |pair| pair.0), Ok(1));
+        assert_eq!(CommonVec::binary_search_by_key(&pairs, &10, |pair| pair.0), Err(3));
+    }
+
+    // Sort unstable -------------------------------------------------------------------------------
+
+    #[test]
+    fn sort_unstable() {
+        let mut vec = StaticVec::<i32, 32>::from_fn(|i| 15 - i as i32);
+
+        vec.sort_unstable();
+        assert_eq!(vec.len(), 32);
+        assert!(vec.iter().copied().eq(-16..16));
+
+        vec.sort_unstable_by_key(|x| -x);
+        assert_eq!(vec.len(), 32);
+        assert!(vec.iter().copied().eq((-16..16).rev()));
+
+        vec.sort_unstable_by(|a, b| a.cmp(b));
+        assert_eq!(vec.len(), 32);
+        assert!(vec.iter().copied().eq(-16..16));
+
+        let mut empty = StaticVec::<i32, 32>::new();
+        empty.sort_unstable();
+        assert!(empty.is_empty());
+    }
+}
@@ -0,0 +1,30 @@
+// =================================================================================================
+// Copyright (c) 2023 Viet-Hoa Do <doviethoa@doviethoa.com>
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// =================================================================================================
+
+//! Runs the unit tests of the importer that generates the source code from the standard library.
+//!
+//! The importer is part of the build script, hence its tests can't be run from there.
+
+#![allow(clippy::needless_return)]
+
+#[path = "../builder/importer.rs"]
+mod importer;
+
+#[allow(dead_code)]
+#[path = "../builder/std_io.rs"]
+mod std_io;